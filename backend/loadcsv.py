@@ -52,24 +52,19 @@ hostname = sys.argv[2]
 apiKey = getApiKey(hostname)
 df = pd.read_excel(filepath)
 
-print('===> Adding New Article')
-article_data = postJSON(f'{hostname}/critica/article/new',
+# rows are positions and columns are variants, empty cells are skipped
+sections = [
+    [None if pd.isna(section_text) else section_text for section_text in row]
+    for _, row in df.iterrows()
+]
+
+print('===> Importing New Article')
+article_data = postJSON(f'{hostname}/critica/article/import',
                         {
                             'title':prompt('===> Enter Title:'),
                             'durationEstimate': 10*60*1000, # 10 min
+                            'sections': sections,
                             'apiKey':apiKey
                         })['Ok']
 
-
-for position, row in df.iterrows():
-  for variant, section_text in enumerate(row):
-      print(f'> Adding variant {variant} at position {position}')
-      postJSON(f'{hostname}/critica/article_section/new',
-               {
-                  'articleId': article_data['article']['articleId'],
-                  'position': position,
-                  'variant': variant,
-                  'sectionText': section_text,
-                  'active': True,
-                  'apiKey': apiKey
-               })['Ok']
+print(f"> Imported article {article_data['article']['articleId']}")
@@ -7,6 +7,23 @@ CREATE DATABASE critica;
 -- Creator User Id (if applicable)
 -- Everything else
 
+-- players who haven't signed up with the auth service
+-- a guest acts with the user id -guest_id, so it never collides with an auth service user
+drop table if exists guest cascade;
+create table guest(
+  guest_id bigserial primary key,
+  creation_time bigint not null default extract(epoch from now()) * 1000
+);
+
+-- a user taking over the history of a guest after signing up
+drop table if exists guest_claim cascade;
+create table guest_claim(
+  guest_claim_id bigserial primary key,
+  creation_time bigint not null default extract(epoch from now()) * 1000,
+  creator_user_id bigint not null,
+  guest_id bigint not null unique references guest(guest_id)
+);
+
 drop table if exists article cascade;
 create table article(
   article_id bigserial primary key,
@@ -25,6 +42,15 @@ create table article_data(
   title text not null,
   -- how long is the article expected to read
   duration_estimate bigint not null,
+  -- bibliographic information, only shown to players once they've finished the article
+  authors text[] not null default '{}',
+  -- journal or venue the article was published in
+  venue text,
+  year bigint,
+  doi text,
+  source_url text,
+  abstract_text text,
+  license text,
   -- is the article still visible
   active bool not null
 );
@@ -38,6 +64,42 @@ create view recent_article_data as
   ) maxids
   on maxids.id = ad.article_data_id;
 
+-- a label on an article, like the field it comes from or how hard it is
+drop table if exists article_tag cascade;
+create table article_tag(
+  article_tag_id bigserial primary key,
+  creation_time bigint not null default extract(epoch from now()) * 1000,
+  creator_user_id bigint not null,
+  article_id bigint not null references article(article_id),
+  -- FIELD or DIFFICULTY
+  kind text not null,
+  -- lowercase, like biology or hard
+  name text not null,
+  -- is the tag still on the article
+  active bool not null
+);
+
+create view recent_article_tag as
+  select at.* from article_tag at
+  inner join (
+   select max(article_tag_id) id
+   from article_tag
+   group by article_id, kind, name
+  ) maxids
+  on maxids.id = at.article_tag_id;
+
+-- a model or program that produces fake sections
+-- immutable: a change in settings should be registered as a new generator
+drop table if exists generator cascade;
+create table generator(
+  generator_id bigserial primary key,
+  creation_time bigint not null default extract(epoch from now()) * 1000,
+  creator_user_id bigint not null,
+  name text not null,
+  version text not null,
+  -- prompt, sampling settings, etc
+  parameters jsonb not null
+);
 
 -- article section data
 drop table if exists article_section cascade;
@@ -49,6 +111,8 @@ create table article_section(
   position bigint not null,
   variant bigint not null,
   section_text text not null,
+  -- what produced this section, if known
+  generator_id bigint references generator(generator_id),
   active bool not null
 );
 
@@ -60,3 +124,203 @@ create view recent_article_section as
    group by article_id, position, variant
   ) maxids
   on maxids.id = a_s.article_section_id;
+
+-- the article everyone plays on a given day
+-- recorded the first time the day's challenge is requested
+drop table if exists daily_challenge cascade;
+create table daily_challenge(
+  daily_challenge_id bigserial primary key,
+  creation_time bigint not null default extract(epoch from now()) * 1000,
+  -- days since the unix epoch, in UTC
+  day bigint not null unique,
+  article_id bigint not null references article(article_id)
+);
+
+-- a player's run through an article, one position at a time
+-- creation_time is when the session was started
+drop table if exists play_session cascade;
+create table play_session(
+  play_session_id bigserial primary key,
+  creation_time bigint not null default extract(epoch from now()) * 1000,
+  creator_user_id bigint not null,
+  article_id bigint not null references article(article_id),
+  -- set if this session is the player's one attempt at a daily challenge
+  daily_challenge_id bigint references daily_challenge(daily_challenge_id),
+  -- two alternative forced choice: each presentation is the true completion and a single fake
+  -- the fake is whichever has been shown least often in forced choice at that position
+  forced_choice bool not null,
+  -- false for replays of an article the player already had a session on, whose answers they may know
+  -- guesses made in unscored sessions don't count towards leaderboards or ratings
+  scored bool not null
+);
+
+-- invariant: play_session_id is valid
+drop table if exists play_session_data cascade;
+create table play_session_data(
+  play_session_data_id bigserial primary key,
+  creation_time bigint not null default extract(epoch from now()) * 1000,
+  creator_user_id bigint not null,
+  play_session_id bigint not null references play_session(play_session_id),
+  -- the position the player is currently on
+  position bigint not null,
+  -- set once the last position has been answered
+  finish_time bigint,
+  -- set if the player gave up on the session
+  abandon_time bigint
+);
+
+create view recent_play_session_data as
+  select psd.* from play_session_data psd
+  inner join (
+   select max(play_session_data_id) id
+   from play_session_data
+   group by play_session_id
+  ) maxids
+  on maxids.id = psd.play_session_data_id;
+
+-- a set of options served to a player for one position of an article
+drop table if exists presentation cascade;
+create table presentation(
+  presentation_id bigserial primary key,
+  creation_time bigint not null default extract(epoch from now()) * 1000,
+  creator_user_id bigint not null,
+  play_session_id bigint not null references play_session(play_session_id),
+  article_id bigint not null references article(article_id),
+  position bigint not null
+);
+
+-- invariant: options are inserted in shuffled order, so their ids say nothing about the variant
+drop table if exists presentation_option cascade;
+create table presentation_option(
+  presentation_option_id bigserial primary key,
+  presentation_id bigint not null references presentation(presentation_id),
+  article_section_id bigint not null references article_section(article_section_id)
+);
+
+-- a player's pick of the true completion from a presentation
+drop table if exists guess cascade;
+create table guess(
+  guess_id bigserial primary key,
+  creation_time bigint not null default extract(epoch from now()) * 1000,
+  creator_user_id bigint not null,
+  article_id bigint not null references article(article_id),
+  position bigint not null,
+  -- only one guess may be made per presentation
+  presentation_id bigint not null unique references presentation(presentation_id),
+  presentation_option_id bigint not null references presentation_option(presentation_option_id),
+  -- the section the player chose
+  article_section_id bigint not null references article_section(article_section_id),
+  -- was the chosen section the true completion (variant 0)
+  correct bool not null,
+  -- a correct pick is worth one point per fake it was presented alongside
+  -- for a ranking this scores the first option, the full ranking is scored on ranking.points
+  points bigint not null,
+  -- milliseconds between the presentation being served and the guess arriving, as measured by the server
+  latency bigint not null,
+  -- the player's stated probability that their pick is the true completion, if they gave one
+  confidence float8,
+  -- brier score of the confidence, set whenever confidence is
+  brier_score float8
+);
+
+-- a span of a fake that a player marked as giving it away, made after guessing
+-- offsets count unicode characters of the section's section_text, end exclusive
+drop table if exists annotation cascade;
+create table annotation(
+  annotation_id bigserial primary key,
+  creation_time bigint not null default extract(epoch from now()) * 1000,
+  creator_user_id bigint not null,
+  guess_id bigint not null references guess(guess_id),
+  article_section_id bigint not null references article_section(article_section_id),
+  start_offset bigint not null,
+  end_offset bigint not null,
+  -- one of FACTUAL_ERROR, REPETITION or STYLE
+  reason text
+);
+
+-- a player's ordering of every option of a presentation, from most to least likely real
+-- the first option is recorded as the guess, scored by how far down the true completion landed
+drop table if exists ranking cascade;
+create table ranking(
+  ranking_id bigserial primary key,
+  creation_time bigint not null default extract(epoch from now()) * 1000,
+  creator_user_id bigint not null,
+  guess_id bigint not null unique references guess(guess_id),
+  presentation_id bigint not null unique references presentation(presentation_id),
+  -- most likely real first
+  presentation_option_ids bigint[] not null,
+  -- zero based index of the true completion in presentation_option_ids, null if it wasn't an option
+  true_rank bigint,
+  -- one point per fake ranked below the true completion
+  -- kept apart from guess.points, which scores only the first option so rankings don't outscore single picks
+  points bigint not null
+);
+
+-- append only history of generator ratings, one row per rating change
+-- a generator wins when its fake is picked over the true completion, and loses otherwise
+drop table if exists generator_rating cascade;
+create table generator_rating(
+  generator_rating_id bigserial primary key,
+  creation_time bigint not null default extract(epoch from now()) * 1000,
+  generator_id bigint not null references generator(generator_id),
+  -- the guess that caused this change
+  guess_id bigint not null references guess(guess_id),
+  -- elo rating, the true completion is fixed at 1500
+  rating double precision not null,
+  win_count bigint not null,
+  loss_count bigint not null
+);
+
+create view recent_generator_rating as
+  select gr.* from generator_rating gr
+  inner join (
+   select max(generator_rating_id) id
+   from generator_rating
+   group by generator_id
+  ) maxids
+  on maxids.id = gr.generator_rating_id;
+
+-- append only history of player ratings, one row per guess
+-- players are rated against the difficulty of the position they guessed on
+drop table if exists user_rating cascade;
+create table user_rating(
+  user_rating_id bigserial primary key,
+  creation_time bigint not null default extract(epoch from now()) * 1000,
+  creator_user_id bigint not null,
+  guess_id bigint not null references guess(guess_id),
+  -- elo rating
+  rating double precision not null,
+  guess_count bigint not null
+);
+
+create view recent_user_rating as
+  select ur.* from user_rating ur
+  inner join (
+   select max(user_rating_id) id
+   from user_rating
+   group by creator_user_id
+  ) maxids
+  on maxids.id = ur.user_rating_id;
+
+-- append only history of how hard each position of an article is, one row per guess
+-- a position wins whenever a player picks a fake
+drop table if exists position_difficulty cascade;
+create table position_difficulty(
+  position_difficulty_id bigserial primary key,
+  creation_time bigint not null default extract(epoch from now()) * 1000,
+  article_id bigint not null references article(article_id),
+  position bigint not null,
+  guess_id bigint not null references guess(guess_id),
+  -- elo rating, on the same scale as user_rating
+  rating double precision not null,
+  guess_count bigint not null
+);
+
+create view recent_position_difficulty as
+  select pd.* from position_difficulty pd
+  inner join (
+   select max(position_difficulty_id) id
+   from position_difficulty
+   group by article_id, position
+  ) maxids
+  on maxids.id = pd.position_difficulty_id;
@@ -0,0 +1,87 @@
+use super::db_types::*;
+use super::utils::current_time_millis;
+use tokio_postgres::GenericClient;
+
+impl From<tokio_postgres::row::Row> for Annotation {
+  // select * from annotation order only, otherwise it will fail
+  fn from(row: tokio_postgres::Row) -> Annotation {
+    Annotation {
+      annotation_id: row.get("annotation_id"),
+      creation_time: row.get("creation_time"),
+      creator_user_id: row.get("creator_user_id"),
+      guess_id: row.get("guess_id"),
+      article_section_id: row.get("article_section_id"),
+      start_offset: row.get("start_offset"),
+      end_offset: row.get("end_offset"),
+      reason: row.get("reason"),
+    }
+  }
+}
+
+pub async fn add(
+  con: &mut impl GenericClient,
+  creator_user_id: i64,
+  guess_id: i64,
+  article_section_id: i64,
+  start_offset: i64,
+  end_offset: i64,
+  reason: Option<String>,
+) -> Result<Annotation, tokio_postgres::Error> {
+  let creation_time = current_time_millis();
+
+  let annotation_id = con
+    .query_one(
+      "INSERT INTO
+       annotation(
+           creation_time,
+           creator_user_id,
+           guess_id,
+           article_section_id,
+           start_offset,
+           end_offset,
+           reason
+       )
+       VALUES ($1, $2, $3, $4, $5, $6, $7)
+       RETURNING annotation_id
+      ",
+      &[
+        &creation_time,
+        &creator_user_id,
+        &guess_id,
+        &article_section_id,
+        &start_offset,
+        &end_offset,
+        &reason,
+      ],
+    )
+    .await?
+    .get(0);
+
+  Ok(Annotation {
+    annotation_id,
+    creation_time,
+    creator_user_id,
+    guess_id,
+    article_section_id,
+    start_offset,
+    end_offset,
+    reason,
+  })
+}
+
+pub async fn get_by_article_section_id(
+  con: &mut impl GenericClient,
+  article_section_id: i64,
+) -> Result<Vec<Annotation>, tokio_postgres::Error> {
+  let results = con
+    .query(
+      "SELECT * FROM annotation WHERE article_section_id=$1 ORDER BY annotation_id",
+      &[&article_section_id],
+    )
+    .await?
+    .into_iter()
+    .map(|x| x.into())
+    .collect();
+
+  Ok(results)
+}
@@ -35,6 +35,20 @@ pub fn api(
             warp::path!("public" / "info"),
             handlers::info,
         ),
+        adapter(
+            config.clone(),
+            db.clone(),
+            auth_service.clone(),
+            warp::path!("public" / "guest" / "new"),
+            handlers::guest_new,
+        ),
+        adapter(
+            config.clone(),
+            db.clone(),
+            auth_service.clone(),
+            warp::path!("public" / "guest" / "claim"),
+            handlers::guest_claim_new,
+        ),
         adapter(
             config.clone(),
             db.clone(),
@@ -42,6 +56,13 @@ pub fn api(
             warp::path!("public" / "article" / "new"),
             handlers::article_new,
         ),
+        adapter(
+            config.clone(),
+            db.clone(),
+            auth_service.clone(),
+            warp::path!("public" / "article" / "import"),
+            handlers::article_import,
+        ),
         adapter(
             config.clone(),
             db.clone(),
@@ -56,6 +77,76 @@ pub fn api(
             warp::path!("public" / "article_section" / "new"),
             handlers::article_section_new,
         ),
+        adapter(
+            config.clone(),
+            db.clone(),
+            auth_service.clone(),
+            warp::path!("public" / "article_tag" / "new"),
+            handlers::article_tag_new,
+        ),
+        adapter(
+            config.clone(),
+            db.clone(),
+            auth_service.clone(),
+            warp::path!("public" / "generator" / "new"),
+            handlers::generator_new,
+        ),
+        adapter(
+            config.clone(),
+            db.clone(),
+            auth_service.clone(),
+            warp::path!("public" / "play_session" / "new"),
+            handlers::play_session_new,
+        ),
+        adapter(
+            config.clone(),
+            db.clone(),
+            auth_service.clone(),
+            warp::path!("public" / "play_session" / "abandon"),
+            handlers::play_session_abandon,
+        ),
+        adapter(
+            config.clone(),
+            db.clone(),
+            auth_service.clone(),
+            warp::path!("public" / "presentation" / "new"),
+            handlers::presentation_new,
+        ),
+        adapter(
+            config.clone(),
+            db.clone(),
+            auth_service.clone(),
+            warp::path!("public" / "guess" / "new"),
+            handlers::guess_new,
+        ),
+        adapter(
+            config.clone(),
+            db.clone(),
+            auth_service.clone(),
+            warp::path!("public" / "ranking" / "new"),
+            handlers::ranking_new,
+        ),
+        adapter(
+            config.clone(),
+            db.clone(),
+            auth_service.clone(),
+            warp::path!("public" / "annotation" / "new"),
+            handlers::annotation_new,
+        ),
+        adapter(
+            config.clone(),
+            db.clone(),
+            auth_service.clone(),
+            warp::path!("public" / "play" / "next"),
+            handlers::play_next,
+        ),
+        adapter(
+            config.clone(),
+            db.clone(),
+            auth_service.clone(),
+            warp::path!("public" / "daily" / "play"),
+            handlers::daily_challenge_play,
+        ),
         adapter(
             config.clone(),
             db.clone(),
@@ -77,6 +168,118 @@ pub fn api(
             warp::path!("public" / "article_section" / "view"),
             handlers::article_section_view,
         ),
+        adapter(
+            config.clone(),
+            db.clone(),
+            auth_service.clone(),
+            warp::path!("public" / "article_section" / "stats"),
+            handlers::article_section_stats,
+        ),
+        adapter(
+            config.clone(),
+            db.clone(),
+            auth_service.clone(),
+            warp::path!("public" / "article" / "latency_stats"),
+            handlers::article_latency_stats,
+        ),
+        adapter(
+            config.clone(),
+            db.clone(),
+            auth_service.clone(),
+            warp::path!("public" / "article" / "bibliography"),
+            handlers::article_bibliography_view,
+        ),
+        adapter(
+            config.clone(),
+            db.clone(),
+            auth_service.clone(),
+            warp::path!("public" / "article" / "revert"),
+            handlers::article_revert,
+        ),
+        adapter(
+            config.clone(),
+            db.clone(),
+            auth_service.clone(),
+            warp::path!("public" / "article" / "history"),
+            handlers::article_history_view,
+        ),
+        adapter(
+            config.clone(),
+            db.clone(),
+            auth_service.clone(),
+            warp::path!("public" / "article_data" / "diff"),
+            handlers::article_data_diff,
+        ),
+        adapter(
+            config.clone(),
+            db.clone(),
+            auth_service.clone(),
+            warp::path!("public" / "article_section" / "diff"),
+            handlers::article_section_diff,
+        ),
+        adapter(
+            config.clone(),
+            db.clone(),
+            auth_service.clone(),
+            warp::path!("public" / "annotation" / "heatmap"),
+            handlers::annotation_heatmap,
+        ),
+        adapter(
+            config.clone(),
+            db.clone(),
+            auth_service.clone(),
+            warp::path!("public" / "article_tag" / "view"),
+            handlers::article_tag_view,
+        ),
+        adapter(
+            config.clone(),
+            db.clone(),
+            auth_service.clone(),
+            warp::path!("public" / "generator" / "view"),
+            handlers::generator_view,
+        ),
+        adapter(
+            config.clone(),
+            db.clone(),
+            auth_service.clone(),
+            warp::path!("public" / "generator" / "leaderboard"),
+            handlers::generator_leaderboard,
+        ),
+        adapter(
+            config.clone(),
+            db.clone(),
+            auth_service.clone(),
+            warp::path!("public" / "play_session_data" / "view"),
+            handlers::play_session_data_view,
+        ),
+        adapter(
+            config.clone(),
+            db.clone(),
+            auth_service.clone(),
+            warp::path!("public" / "guess" / "view"),
+            handlers::guess_view,
+        ),
+        adapter(
+            config.clone(),
+            db.clone(),
+            auth_service.clone(),
+            warp::path!("public" / "leaderboard" / "view"),
+            handlers::leaderboard_view,
+        ),
+        adapter(
+            config.clone(),
+            db.clone(),
+            auth_service.clone(),
+            warp::path!("public" / "user_stats" / "view"),
+            handlers::user_stats_view,
+        ),
+        adapter(
+            config.clone(),
+            db.clone(),
+            auth_service.clone(),
+            warp::path!("public" / "daily" / "view"),
+            handlers::daily_challenge_view,
+        ),
         adapter(
             config.clone(),
             db.clone(),
@@ -90,6 +293,20 @@ pub fn api(
             auth_service.clone(),
             warp::path!("public" / "article_section" / "view_public"),
             handlers::article_section_public_view,
+        ),
+        adapter(
+            config.clone(),
+            db.clone(),
+            auth_service.clone(),
+            warp::path!("public" / "article_tag" / "view_public"),
+            handlers::article_tag_public_view,
+        ),
+        adapter(
+            config.clone(),
+            db.clone(),
+            auth_service.clone(),
+            warp::path!("public" / "article_tag" / "count_public"),
+            handlers::article_tag_count_public_view,
         )
     )
     .recover(handle_rejection)
@@ -2,6 +2,7 @@ use super::db_types::*;
 use super::utils::current_time_millis;
 use tokio_postgres::GenericClient;
 use super::request;
+use super::article_tag_service;
 
 impl From<tokio_postgres::row::Row> for ArticleData {
   // select * from article_data order only, otherwise it will fail
@@ -13,6 +14,15 @@ impl From<tokio_postgres::row::Row> for ArticleData {
       article_id: row.get("article_id"),
       title: row.get("title"),
       duration_estimate: row.get("duration_estimate"),
+      bibliography: Bibliography {
+        authors: row.get("authors"),
+        venue: row.get("venue"),
+        year: row.get("year"),
+        doi: row.get("doi"),
+        source_url: row.get("source_url"),
+        abstract_text: row.get("abstract_text"),
+        license: row.get("license"),
+      },
       active:row.get("active"),
     }
   }
@@ -24,6 +34,7 @@ pub async fn add(
   article_id: i64,
   title: String,
   duration_estimate: i64,
+  bibliography: Bibliography,
   active: bool,
 ) -> Result<ArticleData, tokio_postgres::Error> {
   let creation_time = current_time_millis();
@@ -37,9 +48,16 @@ pub async fn add(
            article_id,
            title,
            duration_estimate,
+           authors,
+           venue,
+           year,
+           doi,
+           source_url,
+           abstract_text,
+           license,
            active
        )
-       VALUES ($1, $2, $3, $4, $5, $6)
+       VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)
        RETURNING article_data_id
       ",
       &[
@@ -48,6 +66,13 @@ pub async fn add(
         &article_id,
         &title,
         &duration_estimate,
+        &bibliography.authors,
+        &bibliography.venue,
+        &bibliography.year,
+        &bibliography.doi,
+        &bibliography.source_url,
+        &bibliography.abstract_text,
+        &bibliography.license,
         &active,
       ],
     )
@@ -62,6 +87,7 @@ pub async fn add(
     article_id,
     title,
     duration_estimate,
+    bibliography,
     active,
   })
 }
@@ -80,12 +106,102 @@ pub async fn get_by_article_data_id(
   Ok(result)
 }
 
+// every revision of the article's data, oldest first
+pub async fn get_by_article_id(
+  con: &mut impl GenericClient,
+  article_id: i64,
+) -> Result<Vec<ArticleData>, tokio_postgres::Error> {
+  let results = con
+    .query(
+      "SELECT * FROM article_data WHERE article_id=$1 ORDER BY article_data_id",
+      &[&article_id],
+    )
+    .await?
+    .into_iter()
+    .map(|x| x.into())
+    .collect();
+  Ok(results)
+}
+
+pub async fn get_recent_by_article_id(
+  con: &mut impl GenericClient,
+  article_id: i64,
+) -> Result<Option<ArticleData>, tokio_postgres::Error> {
+  let result = con
+    .query_opt(
+      "SELECT * FROM recent_article_data WHERE article_id=$1",
+      &[&article_id],
+    )
+    .await?
+    .map(|x| x.into());
+  Ok(result)
+}
+
+// the revision of the article's data that was current at the given time
+pub async fn get_as_of_by_article_id(
+  con: &mut impl GenericClient,
+  article_id: i64,
+  time: i64,
+) -> Result<Option<ArticleData>, tokio_postgres::Error> {
+  let result = con
+    .query_opt(
+      "SELECT * FROM article_data WHERE article_id=$1 AND creation_time <= $2
+       ORDER BY article_data_id DESC LIMIT 1",
+      &[&article_id, &time],
+    )
+    .await?
+    .map(|x| x.into());
+  Ok(result)
+}
+
+// an active, playable article that none of the given users have started a session on
+// excluded_article_id is left out even if it would otherwise qualify
+pub async fn get_recent_unplayed(
+  con: &mut impl GenericClient,
+  creator_user_ids: &[i64],
+  excluded_article_id: Option<i64>,
+  strategy: request::PlayNextStrategy,
+) -> Result<Option<ArticleData>, tokio_postgres::Error> {
+  let sql = [
+    "SELECT ad.* FROM recent_article_data ad",
+    " INNER JOIN article a ON ad.article_id = a.article_id",
+    " WHERE ad.active",
+    " AND EXISTS (",
+    "   SELECT 1 FROM recent_article_section ase",
+    "   WHERE ase.article_id = ad.article_id AND ase.active",
+    " )",
+    " AND NOT EXISTS (",
+    "   SELECT 1 FROM play_session ps",
+    "   WHERE ps.article_id = ad.article_id AND ps.creator_user_id = ANY($1)",
+    " )",
+    " AND ($2::bigint IS NULL OR ad.article_id != $2)",
+    match strategy {
+      request::PlayNextStrategy::Random => " ORDER BY random()",
+      request::PlayNextStrategy::LeastPlayed => {
+        " ORDER BY (SELECT count(*) FROM play_session ps WHERE ps.article_id = ad.article_id), ad.article_id"
+      }
+      request::PlayNextStrategy::Newest => " ORDER BY a.creation_time DESC, ad.article_id DESC",
+    },
+    " LIMIT 1",
+  ]
+  .join("\n");
+
+  let stmnt = con.prepare(&sql).await?;
+
+  let result = con
+    .query_opt(&stmnt, &[&creator_user_ids, &excluded_article_id])
+    .await?
+    .map(|row| row.into());
+
+  Ok(result)
+}
+
 pub async fn query(
   con: &mut impl GenericClient,
   props: request::ArticleDataViewProps,
 ) -> Result<Vec<ArticleData>, tokio_postgres::Error> {
   let sql = [
-    if props.only_recent {
+    if props.only_recent && props.as_of.is_none() {
       "SELECT ad.* FROM recent_article_data ad"
     } else {
       "SELECT ad.* FROM article_data ad"
@@ -101,12 +217,33 @@ pub async fn query(
     " AND ($7::bigint    IS NULL OR ad.duration_estimate >= $7)",
     " AND ($8::bigint    IS NULL OR ad.duration_estimate <= $8)",
     " AND ($9::bool      IS NULL OR ad.active = $9)",
+    " AND ($10::bigint   IS NULL OR ad.article_data_id = (",
+    "   SELECT max(x.article_data_id) FROM article_data x",
+    "   WHERE x.article_id = ad.article_id AND x.creation_time <= $10",
+    " ))",
+    " AND ($11::text[]   IS NULL OR ad.authors && $11)",
+    " AND ($12::text[]   IS NULL OR ad.venue = ANY($12))",
+    " AND ($13::bigint   IS NULL OR ad.year >= $13)",
+    " AND ($14::bigint   IS NULL OR ad.year <= $14)",
+    " AND ($15::text[]   IS NULL OR ad.doi = ANY($15))",
+    " AND ($16::text[]   IS NULL OR ad.license = ANY($16))",
+    // every kind filtered on must have an active tag matching one of that kind's names
+    " AND ($17::text[]   IS NULL OR NOT EXISTS (",
+    "   SELECT 1 FROM unnest($17::text[]) f(kind)",
+    "   WHERE NOT EXISTS (",
+    "     SELECT 1 FROM recent_article_tag at",
+    "     INNER JOIN unnest($17::text[], $18::text[]) g(kind, name) ON g.kind = at.kind AND g.name = at.name",
+    "     WHERE at.article_id = ad.article_id AND at.active AND at.kind = f.kind",
+    "   )",
+    " ))",
     " ORDER BY ad.article_data_id",
   ]
   .join("\n");
 
   let stmnt = con.prepare(&sql).await?;
 
+  let (tag_kinds, tag_names) = article_tag_service::split_filters(props.tag);
+
   let results = con
     .query(
       &stmnt,
@@ -120,6 +257,15 @@ pub async fn query(
         &props.min_duration_estimate,
         &props.max_duration_estimate,
         &props.active,
+        &props.as_of,
+        &props.author,
+        &props.venue,
+        &props.min_year,
+        &props.max_year,
+        &props.doi,
+        &props.license,
+        &tag_kinds,
+        &tag_names,
       ],
     )
     .await?
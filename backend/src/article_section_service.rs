@@ -14,12 +14,14 @@ impl From<tokio_postgres::row::Row> for ArticleSection {
       position: row.get("position"),
       variant: row.get("variant"),
       section_text: row.get("section_text"),
+      generator_id: row.get("generator_id"),
       active: row.get("active"),
     }
   }
 }
 
 // TODO we need to figure out a way to make scheduled and unscheduled articles work better
+#[allow(clippy::too_many_arguments)]
 pub async fn add(
   con: &mut impl GenericClient,
   creator_user_id: i64,
@@ -27,6 +29,7 @@ pub async fn add(
   position: i64,
   variant: i64,
   section_text: String,
+  generator_id: Option<i64>,
   active: bool,
 ) -> Result<ArticleSection, tokio_postgres::Error> {
   let creation_time = current_time_millis();
@@ -41,9 +44,10 @@ pub async fn add(
            position,
            variant,
            section_text,
+           generator_id,
            active
        )
-       VALUES ($1, $2, $3, $4, $5, $6, $7)
+       VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
        RETURNING article_section_id
       ",
       &[
@@ -53,6 +57,7 @@ pub async fn add(
         &position,
         &variant,
         &section_text,
+        &generator_id,
         &active,
       ],
     )
@@ -67,6 +72,7 @@ pub async fn add(
     position,
     variant,
     section_text,
+    generator_id,
     active,
   })
 }
@@ -86,12 +92,130 @@ pub async fn get_by_article_section_id(
   Ok(result)
 }
 
+// every revision of every section of the article, oldest first
+pub async fn get_by_article_id(
+  con: &mut impl GenericClient,
+  article_id: i64,
+) -> Result<Vec<ArticleSection>, tokio_postgres::Error> {
+  let results = con
+    .query(
+      "SELECT * FROM article_section WHERE article_id=$1 ORDER BY article_section_id",
+      &[&article_id],
+    )
+    .await?
+    .into_iter()
+    .map(|x| x.into())
+    .collect();
+
+  Ok(results)
+}
+
+// the current revision of every section of the article, active or not
+pub async fn get_recent_by_article_id(
+  con: &mut impl GenericClient,
+  article_id: i64,
+) -> Result<Vec<ArticleSection>, tokio_postgres::Error> {
+  let results = con
+    .query(
+      "SELECT * FROM recent_article_section WHERE article_id=$1 ORDER BY position, variant",
+      &[&article_id],
+    )
+    .await?
+    .into_iter()
+    .map(|x| x.into())
+    .collect();
+
+  Ok(results)
+}
+
+// the revision of every section of the article that was current at the given time
+pub async fn get_as_of_by_article_id(
+  con: &mut impl GenericClient,
+  article_id: i64,
+  time: i64,
+) -> Result<Vec<ArticleSection>, tokio_postgres::Error> {
+  let results = con
+    .query(
+      "SELECT DISTINCT ON (position, variant) * FROM article_section
+       WHERE article_id=$1 AND creation_time <= $2
+       ORDER BY position, variant, article_section_id DESC",
+      &[&article_id, &time],
+    )
+    .await?
+    .into_iter()
+    .map(|x| x.into())
+    .collect();
+
+  Ok(results)
+}
+
+// the sections a player chooses between at the given position
+pub async fn get_recent_active_by_position(
+  con: &mut impl GenericClient,
+  article_id: i64,
+  position: i64,
+) -> Result<Vec<ArticleSection>, tokio_postgres::Error> {
+  let results = con
+    .query(
+      "SELECT * FROM recent_article_section
+       WHERE article_id=$1 AND position=$2 AND active
+       ORDER BY variant",
+      &[&article_id, &position],
+    )
+    .await?
+    .into_iter()
+    .map(|x| x.into())
+    .collect();
+
+  Ok(results)
+}
+
+// the first position after the given one that still has a visible section
+pub async fn get_next_position(
+  con: &mut impl GenericClient,
+  article_id: i64,
+  after_position: Option<i64>,
+) -> Result<Option<i64>, tokio_postgres::Error> {
+  let result = con
+    .query_one(
+      "SELECT min(position) FROM recent_article_section
+       WHERE article_id=$1 AND active
+       AND ($2::bigint IS NULL OR position > $2)",
+      &[&article_id, &after_position],
+    )
+    .await?
+    .get(0);
+
+  Ok(result)
+}
+
+// the true completions of every position before the given one
+pub async fn get_recent_active_true_before_position(
+  con: &mut impl GenericClient,
+  article_id: i64,
+  position: i64,
+) -> Result<Vec<ArticleSection>, tokio_postgres::Error> {
+  let results = con
+    .query(
+      "SELECT * FROM recent_article_section
+       WHERE article_id=$1 AND position < $2 AND variant = 0 AND active
+       ORDER BY position",
+      &[&article_id, &position],
+    )
+    .await?
+    .into_iter()
+    .map(|x| x.into())
+    .collect();
+
+  Ok(results)
+}
+
 pub async fn query(
   con: &mut impl GenericClient,
   props: super::request::ArticleSectionViewProps,
 ) -> Result<Vec<ArticleSection>, tokio_postgres::Error> {
   let sql = [
-    if props.only_recent {
+    if props.only_recent && props.as_of.is_none() {
       "SELECT ase.* FROM recent_article_section ase"
     } else {
       "SELECT ase.* FROM article_section ase"
@@ -104,13 +228,30 @@ pub async fn query(
     " AND ($5::bigint[] IS NULL OR ase.article_id = ANY($5))",
     " AND ($6::bigint[] IS NULL OR ase.position = ANY($6))",
     " AND ($7::bigint[] IS NULL OR ase.variant = ANY($7))",
-    " AND ($8::bool     IS NULL OR ase.active = $8)",
+    " AND ($8::bigint[] IS NULL OR ase.generator_id = ANY($8))",
+    " AND ($9::bool     IS NULL OR ase.active = $9)",
+    " AND ($10::bigint  IS NULL OR ase.article_section_id = (",
+    "   SELECT max(x.article_section_id) FROM article_section x",
+    "   WHERE x.article_id = ase.article_id AND x.position = ase.position AND x.variant = ase.variant",
+    "   AND x.creation_time <= $10",
+    " ))",
+    // every kind filtered on must have an active tag matching one of that kind's names
+    " AND ($11::text[]  IS NULL OR NOT EXISTS (",
+    "   SELECT 1 FROM unnest($11::text[]) f(kind)",
+    "   WHERE NOT EXISTS (",
+    "     SELECT 1 FROM recent_article_tag at",
+    "     INNER JOIN unnest($11::text[], $12::text[]) g(kind, name) ON g.kind = at.kind AND g.name = at.name",
+    "     WHERE at.article_id = ase.article_id AND at.active AND at.kind = f.kind",
+    "   )",
+    " ))",
     " ORDER BY ase.article_section_id",
   ]
   .join("\n");
 
   let stmnt = con.prepare(&sql).await?;
 
+  let (tag_kinds, tag_names) = super::article_tag_service::split_filters(props.tag);
+
   let results = con
     .query(
       &stmnt,
@@ -122,7 +263,11 @@ pub async fn query(
         &props.article_id,
         &props.position,
         &props.variant,
+        &props.generator_id,
         &props.active,
+        &props.as_of,
+        &tag_kinds,
+        &tag_names,
       ],
     )
     .await?
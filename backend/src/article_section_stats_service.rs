@@ -0,0 +1,52 @@
+use super::db_types::*;
+use tokio_postgres::GenericClient;
+
+impl From<tokio_postgres::row::Row> for ArticleSectionStats {
+  fn from(row: tokio_postgres::Row) -> ArticleSectionStats {
+    ArticleSectionStats {
+      article_id: row.get("article_id"),
+      position: row.get("position"),
+      variant: row.get("variant"),
+      generator_id: row.get("generator_id"),
+      shown_count: row.get("shown_count"),
+      pick_count: row.get("pick_count"),
+    }
+  }
+}
+
+// counts how often each variant was shown in a presentation that got guessed on, and how often it was picked
+// grouped by variant and generator rather than section, so that edits to a section don't split its stats
+// replays are left out, since a player who has seen the article may remember which section is real
+pub async fn query(
+  con: &mut impl GenericClient,
+  article_id: i64,
+  position: Option<Vec<i64>>,
+) -> Result<Vec<ArticleSectionStats>, tokio_postgres::Error> {
+  let sql = [
+    "SELECT a_s.article_id, a_s.position, a_s.variant, a_s.generator_id,",
+    "  count(*) shown_count,",
+    "  count(*) FILTER (WHERE gu.presentation_option_id = po.presentation_option_id) pick_count",
+    " FROM guess gu",
+    " INNER JOIN presentation p ON p.presentation_id = gu.presentation_id",
+    " INNER JOIN play_session ps ON ps.play_session_id = p.play_session_id",
+    " INNER JOIN presentation_option po ON po.presentation_id = gu.presentation_id",
+    " INNER JOIN article_section a_s ON a_s.article_section_id = po.article_section_id",
+    " WHERE ps.scored",
+    " AND a_s.article_id = $1",
+    " AND ($2::bigint[] IS NULL OR a_s.position = ANY($2))",
+    " GROUP BY a_s.article_id, a_s.position, a_s.variant, a_s.generator_id",
+    " ORDER BY a_s.position, a_s.variant, a_s.generator_id",
+  ]
+  .join("\n");
+
+  let stmnt = con.prepare(&sql).await?;
+
+  let results = con
+    .query(&stmnt, &[&article_id, &position])
+    .await?
+    .into_iter()
+    .map(|row| row.into())
+    .collect();
+
+  Ok(results)
+}
@@ -60,10 +60,10 @@ pub async fn query(
   props: request::ArticleViewProps,
 ) -> Result<Vec<Article>, tokio_postgres::Error> {
   let sql = "SELECT g.* FROM article g WHERE 1 = 1
-     AND ($1::bigint IS NULL OR g.article_id = $1)
+     AND ($1::bigint[] IS NULL OR g.article_id = ANY($1))
      AND ($2::bigint IS NULL OR g.creation_time >= $2)
      AND ($3::bigint IS NULL OR g.creation_time <= $3)
-     AND ($4::bigint IS NULL OR g.creator_user_id = $4)
+     AND ($4::bigint[] IS NULL OR g.creator_user_id = ANY($4))
      ORDER BY g.article_id
      ";
 
@@ -0,0 +1,173 @@
+use super::db_types::*;
+use super::utils::current_time_millis;
+use tokio_postgres::GenericClient;
+
+impl From<tokio_postgres::row::Row> for ArticleTag {
+  // select * from article_tag order only, otherwise it will fail
+  fn from(row: tokio_postgres::Row) -> ArticleTag {
+    ArticleTag {
+      article_tag_id: row.get("article_tag_id"),
+      creation_time: row.get("creation_time"),
+      creator_user_id: row.get("creator_user_id"),
+      article_id: row.get("article_id"),
+      kind: row.get("kind"),
+      name: row.get("name"),
+      active: row.get("active"),
+    }
+  }
+}
+
+impl From<tokio_postgres::row::Row> for ArticleTagCount {
+  fn from(row: tokio_postgres::Row) -> ArticleTagCount {
+    ArticleTagCount {
+      kind: row.get("kind"),
+      name: row.get("name"),
+      article_count: row.get("article_count"),
+    }
+  }
+}
+
+pub async fn add(
+  con: &mut impl GenericClient,
+  creator_user_id: i64,
+  article_id: i64,
+  kind: String,
+  name: String,
+  active: bool,
+) -> Result<ArticleTag, tokio_postgres::Error> {
+  let creation_time = current_time_millis();
+
+  let article_tag_id = con
+    .query_one(
+      "INSERT INTO
+       article_tag(
+           creation_time,
+           creator_user_id,
+           article_id,
+           kind,
+           name,
+           active
+       )
+       VALUES ($1, $2, $3, $4, $5, $6)
+       RETURNING article_tag_id
+      ",
+      &[
+        &creation_time,
+        &creator_user_id,
+        &article_id,
+        &kind,
+        &name,
+        &active,
+      ],
+    )
+    .await?
+    .get(0);
+
+  Ok(ArticleTag {
+    article_tag_id,
+    creation_time,
+    creator_user_id,
+    article_id,
+    kind,
+    name,
+    active,
+  })
+}
+
+// tags are stored and matched case insensitively, without surrounding whitespace
+pub fn normalize_name(name: &str) -> String {
+  name.trim().to_lowercase()
+}
+
+// splits tag filters into parallel arrays of kinds and names, for unnesting together in sql
+pub fn split_filters(
+  filters: Option<Vec<super::request::ArticleTagFilter>>,
+) -> (Option<Vec<String>>, Option<Vec<String>>) {
+  match filters {
+    Some(filters) => {
+      let (kinds, names) = filters
+        .into_iter()
+        .map(|x| (x.kind.as_ref().to_string(), normalize_name(&x.name)))
+        .unzip();
+      (Some(kinds), Some(names))
+    }
+    None => (None, None),
+  }
+}
+
+pub async fn query(
+  con: &mut impl GenericClient,
+  props: super::request::ArticleTagViewProps,
+) -> Result<Vec<ArticleTag>, tokio_postgres::Error> {
+  let sql = [
+    if props.only_recent {
+      "SELECT at.* FROM recent_article_tag at"
+    } else {
+      "SELECT at.* FROM article_tag at"
+    },
+    " WHERE 1 = 1",
+    " AND ($1::bigint[] IS NULL OR at.article_tag_id = ANY($1))",
+    " AND ($2::bigint   IS NULL OR at.creation_time >= $2)",
+    " AND ($3::bigint   IS NULL OR at.creation_time <= $3)",
+    " AND ($4::bigint[] IS NULL OR at.creator_user_id = ANY($4))",
+    " AND ($5::bigint[] IS NULL OR at.article_id = ANY($5))",
+    " AND ($6::text     IS NULL OR at.kind = $6)",
+    " AND ($7::text[]   IS NULL OR at.name = ANY($7))",
+    " AND ($8::bool     IS NULL OR at.active = $8)",
+    " ORDER BY at.article_tag_id",
+  ]
+  .join("\n");
+
+  let stmnt = con.prepare(&sql).await?;
+
+  let results = con
+    .query(
+      &stmnt,
+      &[
+        &props.article_tag_id,
+        &props.min_creation_time,
+        &props.max_creation_time,
+        &props.creator_user_id,
+        &props.article_id,
+        &props.kind.map(|x| x.as_ref().to_string()),
+        &props
+          .name
+          .map(|x| x.iter().map(|x| normalize_name(x)).collect::<Vec<_>>()),
+        &props.active,
+      ],
+    )
+    .await?
+    .into_iter()
+    .map(|row| row.into())
+    .collect();
+
+  Ok(results)
+}
+
+// how many active articles carry each tag, most used first
+pub async fn get_counts(
+  con: &mut impl GenericClient,
+  kind: Option<String>,
+) -> Result<Vec<ArticleTagCount>, tokio_postgres::Error> {
+  let sql = [
+    "SELECT at.kind, at.name, count(*) article_count",
+    " FROM recent_article_tag at",
+    " INNER JOIN recent_article_data ad ON ad.article_id = at.article_id",
+    " WHERE at.active AND ad.active",
+    " AND ($1::text IS NULL OR at.kind = $1)",
+    " GROUP BY at.kind, at.name",
+    " ORDER BY article_count DESC, at.kind, at.name",
+  ]
+  .join("\n");
+
+  let stmnt = con.prepare(&sql).await?;
+
+  let results = con
+    .query(&stmnt, &[&kind])
+    .await?
+    .into_iter()
+    .map(|row| row.into())
+    .collect();
+
+  Ok(results)
+}
@@ -0,0 +1,174 @@
+use super::db_types::*;
+use tokio_postgres::GenericClient;
+
+impl From<tokio_postgres::row::Row> for CalibrationBin {
+  fn from(row: tokio_postgres::Row) -> CalibrationBin {
+    CalibrationBin {
+      min_confidence: row.get("min_confidence"),
+      max_confidence: row.get("max_confidence"),
+      guess_count: row.get("guess_count"),
+      correct_count: row.get("correct_count"),
+      mean_confidence: row.get("mean_confidence"),
+      mean_brier_score: row.get("mean_brier_score"),
+    }
+  }
+}
+
+// splits [0, 1] into bin_count equal bins, and summarizes the confident guesses of the given users in each
+// empty bins are left out, and a confidence of exactly 1 goes in the last bin
+// replays are left out, since a player answering from memory tells us nothing about their calibration
+pub async fn get_by_creator_user_ids(
+  con: &mut impl GenericClient,
+  creator_user_ids: &[i64],
+  bin_count: i64,
+) -> Result<Vec<CalibrationBin>, tokio_postgres::Error> {
+  let sql = [
+    "SELECT (b.bin - 1)::float8 / $2::bigint min_confidence,",
+    "  b.bin::float8 / $2::bigint max_confidence,",
+    "  count(*) guess_count,",
+    "  count(*) FILTER (WHERE b.correct) correct_count,",
+    "  avg(b.confidence) mean_confidence,",
+    "  avg(b.brier_score) mean_brier_score",
+    " FROM (",
+    "   SELECT least(width_bucket(g.confidence, 0, 1, $2::bigint::int), $2::bigint) bin, g.*",
+    "   FROM guess g",
+    "   INNER JOIN presentation p ON p.presentation_id = g.presentation_id",
+    "   INNER JOIN play_session ps ON ps.play_session_id = p.play_session_id",
+    "   WHERE ps.scored AND g.confidence IS NOT NULL AND g.creator_user_id = ANY($1)",
+    " ) b",
+    " GROUP BY b.bin",
+    " ORDER BY b.bin",
+  ]
+  .join("\n");
+
+  let stmnt = con.prepare(&sql).await?;
+
+  let results = con
+    .query(&stmnt, &[&creator_user_ids, &bin_count])
+    .await?
+    .into_iter()
+    .map(|row| row.into())
+    .collect();
+
+  Ok(results)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{
+    article_section_service, article_service, guess_service, play_session_service,
+    presentation_option_service, presentation_service,
+  };
+  use tokio_postgres::NoTls;
+
+  // guesses with the given confidences and correctness on the first position of a fresh article
+  async fn add_guesses(
+    con: &mut impl GenericClient,
+    creator_user_id: i64,
+    scored: bool,
+    guesses: &[(f64, bool)],
+  ) -> Result<(), tokio_postgres::Error> {
+    let article = article_service::add(con, creator_user_id).await?;
+    let section = article_section_service::add(
+      con,
+      creator_user_id,
+      article.article_id,
+      0,
+      0,
+      "section".to_string(),
+      None,
+      true,
+    )
+    .await?;
+    let play_session = play_session_service::add(
+      con,
+      creator_user_id,
+      article.article_id,
+      None,
+      false,
+      scored,
+    )
+    .await?;
+    // only one guess may be made per presentation
+    for &(confidence, correct) in guesses {
+      let presentation = presentation_service::add(
+        con,
+        creator_user_id,
+        play_session.play_session_id,
+        article.article_id,
+        0,
+      )
+      .await?;
+      let option = presentation_option_service::add(
+        con,
+        presentation.presentation_id,
+        section.article_section_id,
+      )
+      .await?;
+      let outcome = if correct { 1.0 } else { 0.0 };
+      guess_service::add(
+        con,
+        creator_user_id,
+        article.article_id,
+        0,
+        presentation.presentation_id,
+        option.presentation_option_id,
+        section.article_section_id,
+        correct,
+        0,
+        presentation.creation_time,
+        Some(confidence),
+        Some((confidence - outcome) * (confidence - outcome)),
+      )
+      .await?;
+    }
+    Ok(())
+  }
+
+  // needs a database with the schema loaded, e.g.
+  // CRITICA_TEST_DATABASE_URL="host=/tmp user=postgres dbname=critica" cargo test -- --ignored
+  // everything is written in a transaction that is rolled back
+  #[tokio::test]
+  #[ignore = "needs CRITICA_TEST_DATABASE_URL"]
+  async fn bins_scored_guesses() {
+    let database_url = std::env::var("CRITICA_TEST_DATABASE_URL").unwrap();
+    let (mut client, connection) = tokio_postgres::connect(&database_url, NoTls).await.unwrap();
+    tokio::spawn(connection);
+    let mut sp = client.transaction().await.unwrap();
+
+    let creator_user_id = 1_000_000_000;
+    add_guesses(
+      &mut sp,
+      creator_user_id,
+      true,
+      &[(0.25, false), (0.95, true), (1.0, true)],
+    )
+    .await
+    .unwrap();
+    // replays don't count
+    add_guesses(&mut sp, creator_user_id, false, &[(0.55, true)])
+      .await
+      .unwrap();
+
+    let bins = get_by_creator_user_ids(&mut sp, &[creator_user_id], 10)
+      .await
+      .unwrap();
+    sp.rollback().await.unwrap();
+
+    let summary: Vec<_> = bins
+      .iter()
+      .map(|x| {
+        (
+          x.min_confidence,
+          x.max_confidence,
+          x.guess_count,
+          x.correct_count,
+        )
+      })
+      .collect();
+    // a confidence of exactly 1 goes in the last bin
+    assert_eq!(summary, vec![(0.2, 0.3, 1, 0), (0.9, 1.0, 2, 2)]);
+    assert!((bins[1].mean_confidence - 0.975).abs() < 1e-9);
+  }
+}
@@ -0,0 +1,86 @@
+use super::db_types::*;
+use super::utils::current_time_millis;
+use tokio_postgres::GenericClient;
+
+impl From<tokio_postgres::row::Row> for DailyChallenge {
+  // select * from daily_challenge order only, otherwise it will fail
+  fn from(row: tokio_postgres::Row) -> DailyChallenge {
+    DailyChallenge {
+      daily_challenge_id: row.get("daily_challenge_id"),
+      creation_time: row.get("creation_time"),
+      day: row.get("day"),
+      article_id: row.get("article_id"),
+    }
+  }
+}
+
+pub async fn add(
+  con: &mut impl GenericClient,
+  day: i64,
+  article_id: i64,
+) -> Result<DailyChallenge, tokio_postgres::Error> {
+  let creation_time = current_time_millis();
+
+  let daily_challenge_id = con
+    .query_one(
+      "INSERT INTO
+       daily_challenge(
+           creation_time,
+           day,
+           article_id
+       )
+       VALUES ($1, $2, $3)
+       RETURNING daily_challenge_id
+      ",
+      &[&creation_time, &day, &article_id],
+    )
+    .await?
+    .get(0);
+
+  Ok(DailyChallenge {
+    daily_challenge_id,
+    creation_time,
+    day,
+    article_id,
+  })
+}
+
+pub async fn get_by_day(
+  con: &mut impl GenericClient,
+  day: i64,
+) -> Result<Option<DailyChallenge>, tokio_postgres::Error> {
+  let result = con
+    .query_opt("SELECT * FROM daily_challenge WHERE day=$1", &[&day])
+    .await?
+    .map(|x| x.into());
+
+  Ok(result)
+}
+
+// active, playable articles that could be picked as a daily challenge
+// unless allow_repeats is set, articles that have already been picked are left out
+pub async fn get_candidate_article_ids(
+  con: &mut impl GenericClient,
+  allow_repeats: bool,
+) -> Result<Vec<i64>, tokio_postgres::Error> {
+  let results = con
+    .query(
+      "SELECT ad.article_id FROM recent_article_data ad
+       WHERE ad.active
+       AND EXISTS (
+         SELECT 1 FROM recent_article_section ase
+         WHERE ase.article_id = ad.article_id AND ase.active
+       )
+       AND ($1 OR NOT EXISTS (
+         SELECT 1 FROM daily_challenge dc WHERE dc.article_id = ad.article_id
+       ))
+       ORDER BY ad.article_id",
+      &[&allow_repeats],
+    )
+    .await?
+    .into_iter()
+    .map(|x| x.get(0))
+    .collect();
+
+  Ok(results)
+}
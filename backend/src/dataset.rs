@@ -0,0 +1,406 @@
+// Datasets are exported as JSONL: one ArticleRecord per line, each a self contained JSON object like
+//
+// {
+//   "formatVersion": 1,
+//   "articleId": 12,
+//   "creationTime": 1680000000000,
+//   "creatorUserId": 3,
+//   "title": "...",
+//   "durationEstimate": 600000,
+//   "bibliography": { "authors": ["..."], "venue": "...", "year": 2023, "doi": "...", "sourceUrl": "...", "abstractText": "...", "license": "CC-BY-4.0" },
+//   "active": true,
+//   "generators": [
+//     { "generatorId": 4, "name": "gpt2", "version": "1.5b", "parameters": { "temperature": 0.7 } }
+//   ],
+//   "sections": [
+//     { "position": 0, "variant": 0, "sectionText": "...", "generatorId": null, "creationTime": ..., "creatorUserId": 3 },
+//     { "position": 1, "variant": 1, "sectionText": "...", "generatorId": 4, "creationTime": ..., "creatorUserId": 3 }
+//   ]
+// }
+//
+// Only the current version of the article and of each of its active sections is exported.
+// Articles that wouldn't import, such as those without active sections, are skipped with a warning.
+// Every field of the bibliography may be null, and the bibliography itself may be left out.
+// Variant 0 is the true completion, and every position has one.
+// A section's generatorId refers to one of the generators of the same record.
+// Ids, creation times and creator user ids record where the data came from, and are ignored on import:
+// the article and its generators are recreated with new ids, owned by the importing user.
+
+use clap::Args;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::error::Error;
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::path::PathBuf;
+use tokio_postgres::GenericClient;
+
+use super::article_data_service;
+use super::article_section_service;
+use super::article_service;
+use super::db_types::Bibliography;
+use super::generator_service;
+use super::import;
+use super::request;
+
+static FORMAT_VERSION: i64 = 1;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GeneratorRecord {
+    pub generator_id: i64,
+    pub name: String,
+    pub version: String,
+    pub parameters: serde_json::Value,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SectionRecord {
+    pub position: i64,
+    pub variant: i64,
+    pub section_text: String,
+    pub generator_id: Option<i64>,
+    pub creation_time: i64,
+    pub creator_user_id: i64,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArticleRecord {
+    pub format_version: i64,
+    pub article_id: i64,
+    pub creation_time: i64,
+    pub creator_user_id: i64,
+    pub title: String,
+    pub duration_estimate: i64,
+    pub bibliography: Option<request::Bibliography>,
+    pub active: bool,
+    pub generators: Vec<GeneratorRecord>,
+    pub sections: Vec<SectionRecord>,
+}
+
+#[derive(Args, Clone)]
+pub struct ExportOpts {
+    #[clap(long)]
+    database_url: String,
+    /// may be given more than once, every article is exported if left out
+    #[clap(long)]
+    article_id: Vec<i64>,
+    /// the jsonl file to write
+    #[clap(long)]
+    file: PathBuf,
+}
+
+#[derive(Args, Clone)]
+pub struct ImportDatasetOpts {
+    #[clap(long)]
+    database_url: String,
+    /// a jsonl file written by export
+    #[clap(long)]
+    file: PathBuf,
+    /// the user the articles are created for
+    #[clap(long)]
+    creator_user_id: i64,
+    /// validate the file against the database without writing anything
+    #[clap(long)]
+    dry_run: bool,
+}
+
+async fn get_article_record(
+    con: &mut impl GenericClient,
+    article_id: i64,
+) -> Result<ArticleRecord, Box<dyn Error>> {
+    let article = article_service::get_by_article_id(con, article_id)
+        .await?
+        .ok_or(format!("article {} doesn't exist", article_id))?;
+
+    let article_data = article_data_service::get_recent_by_article_id(con, article_id)
+        .await?
+        .ok_or(format!("article {} has no data", article_id))?;
+
+    let article_sections = article_section_service::query(
+        con,
+        request::ArticleSectionViewProps {
+            article_section_id: None,
+            min_creation_time: None,
+            max_creation_time: None,
+            creator_user_id: None,
+            article_id: Some(vec![article_id]),
+            position: None,
+            variant: None,
+            generator_id: None,
+            active: Some(true),
+            only_recent: true,
+            as_of: None,
+            tag: None,
+            api_key: String::from(""),
+        },
+    )
+    .await?;
+
+    let mut generators = vec![];
+    let mut generator_ids = HashSet::new();
+    for generator_id in article_sections.iter().filter_map(|x| x.generator_id) {
+        if generator_ids.insert(generator_id) {
+            let generator = generator_service::get_by_generator_id(con, generator_id)
+                .await?
+                .ok_or(format!("generator {} doesn't exist", generator_id))?;
+            generators.push(GeneratorRecord {
+                generator_id: generator.generator_id,
+                name: generator.name,
+                version: generator.version,
+                parameters: generator.parameters,
+            });
+        }
+    }
+
+    let mut sections: Vec<SectionRecord> = article_sections
+        .into_iter()
+        .map(|x| SectionRecord {
+            position: x.position,
+            variant: x.variant,
+            section_text: x.section_text,
+            generator_id: x.generator_id,
+            creation_time: x.creation_time,
+            creator_user_id: x.creator_user_id,
+        })
+        .collect();
+    sections.sort_by_key(|x| (x.position, x.variant));
+
+    Ok(ArticleRecord {
+        format_version: FORMAT_VERSION,
+        article_id: article.article_id,
+        creation_time: article.creation_time,
+        creator_user_id: article.creator_user_id,
+        title: article_data.title,
+        duration_estimate: article_data.duration_estimate,
+        bibliography: Some(request::Bibliography {
+            authors: Some(article_data.bibliography.authors),
+            venue: article_data.bibliography.venue,
+            year: article_data.bibliography.year,
+            doi: article_data.bibliography.doi,
+            source_url: article_data.bibliography.source_url,
+            abstract_text: article_data.bibliography.abstract_text,
+            license: article_data.bibliography.license,
+        }),
+        active: article_data.active,
+        generators,
+        sections,
+    })
+}
+
+fn validate_article_record(record: &ArticleRecord) -> Result<(), String> {
+    if record.format_version != FORMAT_VERSION {
+        return Err(format!(
+            "unsupported format version {}",
+            record.format_version
+        ));
+    }
+
+    if record.duration_estimate <= 0 {
+        return Err("duration estimate must be positive".into());
+    }
+
+    if record.sections.is_empty() {
+        return Err("article has no sections".into());
+    }
+
+    let generator_ids: HashSet<i64> = record.generators.iter().map(|x| x.generator_id).collect();
+
+    let mut seen = HashSet::new();
+    for section in &record.sections {
+        if section.position < 0 || section.variant < 0 {
+            return Err("positions and variants can't be negative".into());
+        }
+
+        if !seen.insert((section.position, section.variant)) {
+            return Err(format!(
+                "variant {} at position {} appears more than once",
+                section.variant, section.position
+            ));
+        }
+
+        match section.generator_id {
+            Some(_) if section.variant == 0 => {
+                return Err(format!(
+                    "the true completion at position {} can't have a generator",
+                    section.position
+                ))
+            }
+            Some(generator_id) if !generator_ids.contains(&generator_id) => {
+                return Err(format!("generator {} isn't in the record", generator_id))
+            }
+            _ => {}
+        }
+    }
+
+    for section in &record.sections {
+        if !seen.contains(&(section.position, 0)) {
+            return Err(format!(
+                "position {} has no true completion",
+                section.position
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+// returns the id of the new article
+async fn add_article_record(
+    con: &mut impl GenericClient,
+    creator_user_id: i64,
+    record: ArticleRecord,
+) -> Result<i64, Box<dyn Error>> {
+    let mut generator_ids = HashMap::new();
+    for generator in record.generators {
+        let new_generator = generator_service::add(
+            con,
+            creator_user_id,
+            generator.name,
+            generator.version,
+            generator.parameters,
+        )
+        .await?;
+        generator_ids.insert(generator.generator_id, new_generator.generator_id);
+    }
+
+    let article = article_service::add(con, creator_user_id).await?;
+
+    article_data_service::add(
+        con,
+        creator_user_id,
+        article.article_id,
+        record.title,
+        record.duration_estimate,
+        record
+            .bibliography
+            .map(|x| Bibliography {
+                authors: x.authors.unwrap_or_default(),
+                venue: x.venue,
+                year: x.year,
+                doi: x.doi,
+                source_url: x.source_url,
+                abstract_text: x.abstract_text,
+                license: x.license,
+            })
+            .unwrap_or_default(),
+        record.active,
+    )
+    .await?;
+
+    for section in record.sections {
+        article_section_service::add(
+            con,
+            creator_user_id,
+            article.article_id,
+            section.position,
+            section.variant,
+            section.section_text,
+            section.generator_id.map(|x| generator_ids[&x]),
+            true,
+        )
+        .await?;
+    }
+
+    Ok(article.article_id)
+}
+
+pub async fn export(opts: ExportOpts) -> Result<(), Box<dyn Error>> {
+    let mut client = import::connect(&opts.database_url).await?;
+
+    let article_ids = if opts.article_id.is_empty() {
+        article_service::query(
+            &mut client,
+            request::ArticleViewProps {
+                article_id: None,
+                min_creation_time: None,
+                max_creation_time: None,
+                creator_user_id: None,
+                api_key: String::from(""),
+            },
+        )
+        .await?
+        .into_iter()
+        .map(|x| x.article_id)
+        .collect()
+    } else {
+        opts.article_id
+    };
+
+    let mut exported_count = 0;
+    let mut skipped_count = 0;
+    let mut writer = BufWriter::new(File::create(&opts.file)?);
+    for article_id in &article_ids {
+        let record = get_article_record(&mut client, *article_id).await?;
+        // an article still being written may not import yet, so it's left out rather than breaking the dataset
+        if let Err(e) = validate_article_record(&record) {
+            eprintln!("skipping article {}: {}", article_id, e);
+            skipped_count += 1;
+            continue;
+        }
+        serde_json::to_writer(&mut writer, &record)?;
+        writeln!(writer)?;
+        exported_count += 1;
+    }
+    writer.flush()?;
+
+    println!(
+        "exported {} articles to {}, skipped {}",
+        exported_count,
+        opts.file.display(),
+        skipped_count
+    );
+
+    Ok(())
+}
+
+pub async fn import(opts: ImportDatasetOpts) -> Result<(), Box<dyn Error>> {
+    let mut records = vec![];
+    for (i, line) in BufReader::new(File::open(&opts.file)?).lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let record: ArticleRecord =
+            serde_json::from_str(&line).map_err(|e| format!("line {}: {}", i + 1, e))?;
+        validate_article_record(&record).map_err(|e| format!("line {}: {}", i + 1, e))?;
+        records.push(record);
+    }
+
+    println!(
+        "read {} articles from {}",
+        records.len(),
+        opts.file.display()
+    );
+
+    let mut client = import::connect(&opts.database_url).await?;
+
+    // the whole dataset goes through one transaction, so a dry run is just an import that gets rolled back
+    let mut sp = client.transaction().await?;
+
+    // only reported once the transaction is over, since until then a later record could still fail the import
+    let mut imported = vec![];
+    for record in records {
+        let article_id = record.article_id;
+        let new_article_id = add_article_record(&mut sp, opts.creator_user_id, record).await?;
+        imported.push((article_id, new_article_id));
+    }
+
+    if opts.dry_run {
+        sp.rollback().await?;
+        for (article_id, _) in imported {
+            println!("would import article {}", article_id);
+        }
+        println!("dry run: the file is valid, nothing was written");
+    } else {
+        sp.commit().await?;
+        for (article_id, new_article_id) in imported {
+            println!("article {} imported as {}", article_id, new_article_id);
+        }
+    }
+
+    Ok(())
+}
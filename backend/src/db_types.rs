@@ -1,3 +1,17 @@
+#[derive(Clone, Debug)]
+pub struct Guest {
+  pub guest_id: i64,
+  pub creation_time: i64,
+}
+
+#[derive(Clone, Debug)]
+pub struct GuestClaim {
+  pub guest_claim_id: i64,
+  pub creation_time: i64,
+  pub creator_user_id: i64,
+  pub guest_id: i64,
+}
+
 #[derive(Clone, Debug)]
 pub struct Article {
   pub article_id: i64,
@@ -13,9 +27,50 @@ pub struct ArticleData {
   pub article_id: i64,
   pub title: String,
   pub duration_estimate: i64,
+  pub bibliography: Bibliography,
+  pub active: bool,
+}
+
+#[derive(Clone, Debug)]
+pub struct ArticleTag {
+  pub article_tag_id: i64,
+  pub creation_time: i64,
+  pub creator_user_id: i64,
+  pub article_id: i64,
+  pub kind: String,
+  pub name: String,
   pub active: bool,
 }
 
+// the number of active, visible articles carrying the tag
+#[derive(Clone, Debug)]
+pub struct ArticleTagCount {
+  pub kind: String,
+  pub name: String,
+  pub article_count: i64,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Bibliography {
+  pub authors: Vec<String>,
+  pub venue: Option<String>,
+  pub year: Option<i64>,
+  pub doi: Option<String>,
+  pub source_url: Option<String>,
+  pub abstract_text: Option<String>,
+  pub license: Option<String>,
+}
+
+#[derive(Clone, Debug)]
+pub struct Generator {
+  pub generator_id: i64,
+  pub creation_time: i64,
+  pub creator_user_id: i64,
+  pub name: String,
+  pub version: String,
+  pub parameters: serde_json::Value,
+}
+
 #[derive(Clone, Debug)]
 pub struct ArticleSection {
   pub article_section_id: i64,
@@ -25,6 +80,182 @@ pub struct ArticleSection {
   pub position: i64,
   pub variant: i64,
   pub section_text: String,
+  pub generator_id: Option<i64>,
   pub active: bool,
 }
 
+#[derive(Clone, Debug)]
+pub struct PlaySession {
+  pub play_session_id: i64,
+  pub creation_time: i64,
+  pub creator_user_id: i64,
+  pub article_id: i64,
+  pub daily_challenge_id: Option<i64>,
+  pub forced_choice: bool,
+  pub scored: bool,
+}
+
+#[derive(Clone, Debug)]
+pub struct DailyChallenge {
+  pub daily_challenge_id: i64,
+  pub creation_time: i64,
+  pub day: i64,
+  pub article_id: i64,
+}
+
+#[derive(Clone, Debug)]
+pub struct PlaySessionData {
+  pub play_session_data_id: i64,
+  pub creation_time: i64,
+  pub creator_user_id: i64,
+  pub play_session_id: i64,
+  pub position: i64,
+  pub finish_time: Option<i64>,
+  pub abandon_time: Option<i64>,
+}
+
+#[derive(Clone, Debug)]
+pub struct Presentation {
+  pub presentation_id: i64,
+  pub creation_time: i64,
+  pub creator_user_id: i64,
+  pub play_session_id: i64,
+  pub article_id: i64,
+  pub position: i64,
+}
+
+#[derive(Clone, Debug)]
+pub struct PresentationOption {
+  pub presentation_option_id: i64,
+  pub presentation_id: i64,
+  pub article_section_id: i64,
+}
+
+#[derive(Clone, Debug)]
+pub struct Guess {
+  pub guess_id: i64,
+  pub creation_time: i64,
+  pub creator_user_id: i64,
+  pub article_id: i64,
+  pub position: i64,
+  pub presentation_id: i64,
+  pub presentation_option_id: i64,
+  pub article_section_id: i64,
+  pub correct: bool,
+  pub points: i64,
+  pub latency: i64,
+  pub confidence: Option<f64>,
+  pub brier_score: Option<f64>,
+}
+
+#[derive(Clone, Debug)]
+pub struct Annotation {
+  pub annotation_id: i64,
+  pub creation_time: i64,
+  pub creator_user_id: i64,
+  pub guess_id: i64,
+  pub article_section_id: i64,
+  pub start_offset: i64,
+  pub end_offset: i64,
+  pub reason: Option<String>,
+}
+
+#[derive(Clone, Debug)]
+pub struct Ranking {
+  pub ranking_id: i64,
+  pub creation_time: i64,
+  pub creator_user_id: i64,
+  pub guess_id: i64,
+  pub presentation_id: i64,
+  pub presentation_option_ids: Vec<i64>,
+  pub true_rank: Option<i64>,
+  pub points: i64,
+}
+
+// a guess together with what the player was shown, for offline analysis
+// creator_user_id is the claiming user for guesses made as a claimed guest
+#[derive(Clone, Debug)]
+pub struct GuessRecord {
+  pub creator_user_id: i64,
+  pub play_session_id: i64,
+  pub forced_choice: bool,
+  pub article_id: i64,
+  pub position: i64,
+  pub variants_shown: Vec<i64>,
+  pub variant_chosen: i64,
+  pub generator_id: Option<i64>,
+  pub correct: bool,
+  pub latency: i64,
+  pub confidence: Option<f64>,
+}
+
+// guesses whose confidence fell into [min_confidence, max_confidence)
+#[derive(Clone, Debug)]
+pub struct CalibrationBin {
+  pub min_confidence: f64,
+  pub max_confidence: f64,
+  pub guess_count: i64,
+  pub correct_count: i64,
+  pub mean_confidence: f64,
+  pub mean_brier_score: f64,
+}
+
+// position is none for the summary over every position
+#[derive(Clone, Debug)]
+pub struct LatencyStats {
+  pub position: Option<i64>,
+  pub guess_count: i64,
+  pub mean_latency: Option<f64>,
+  pub median_latency: Option<f64>,
+  pub p90_latency: Option<f64>,
+}
+
+#[derive(Clone, Debug)]
+pub struct GeneratorRating {
+  pub generator_rating_id: i64,
+  pub creation_time: i64,
+  pub generator_id: i64,
+  pub guess_id: i64,
+  pub rating: f64,
+  pub win_count: i64,
+  pub loss_count: i64,
+}
+
+#[derive(Clone, Debug)]
+pub struct UserRating {
+  pub user_rating_id: i64,
+  pub creation_time: i64,
+  pub creator_user_id: i64,
+  pub guess_id: i64,
+  pub rating: f64,
+  pub guess_count: i64,
+}
+
+#[derive(Clone, Debug)]
+pub struct PositionDifficulty {
+  pub position_difficulty_id: i64,
+  pub creation_time: i64,
+  pub article_id: i64,
+  pub position: i64,
+  pub guess_id: i64,
+  pub rating: f64,
+  pub guess_count: i64,
+}
+
+#[derive(Clone, Debug)]
+pub struct LeaderboardEntry {
+  pub creator_user_id: i64,
+  pub points: i64,
+  pub correct_count: i64,
+  pub guess_count: i64,
+}
+
+#[derive(Clone, Debug)]
+pub struct ArticleSectionStats {
+  pub article_id: i64,
+  pub position: i64,
+  pub variant: i64,
+  pub generator_id: Option<i64>,
+  pub shown_count: i64,
+  pub pick_count: i64,
+}
@@ -0,0 +1,190 @@
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DiffKind {
+  Equal,
+  Insert,
+  Delete,
+}
+
+// the most cells the lcs table may have once the common prefix and suffix are trimmed, about 8mb
+static MAX_LCS_CELLS: usize = 1_000_000;
+
+// splits text into runs of whitespace and runs of everything else, so that the tokens join back into the text
+fn tokenize(text: &str) -> Vec<&str> {
+  let mut tokens = vec![];
+  let mut start = 0;
+  let mut last_whitespace = None;
+  for (i, c) in text.char_indices() {
+    let whitespace = c.is_whitespace();
+    if last_whitespace.is_some_and(|x| x != whitespace) {
+      tokens.push(&text[start..i]);
+      start = i;
+    }
+    last_whitespace = Some(whitespace);
+  }
+  if start < text.len() {
+    tokens.push(&text[start..]);
+  }
+  tokens
+}
+
+// word level diff from old to new, via the longest common subsequence of their tokens
+// adjacent tokens of the same kind are merged into one chunk
+// none if the texts differ too widely to diff in reasonable memory
+pub fn diff_words(old: &str, new: &str) -> Option<Vec<(DiffKind, String)>> {
+  let old = tokenize(old);
+  let new = tokenize(new);
+
+  // edits are usually small, so only the part between the common prefix and suffix needs the table
+  let prefix_len = old.iter().zip(&new).take_while(|(a, b)| a == b).count();
+  let suffix_len = old[prefix_len..]
+    .iter()
+    .rev()
+    .zip(new[prefix_len..].iter().rev())
+    .take_while(|(a, b)| a == b)
+    .count();
+  let old_middle = &old[prefix_len..old.len() - suffix_len];
+  let new_middle = &new[prefix_len..new.len() - suffix_len];
+
+  if (old_middle.len() + 1).saturating_mul(new_middle.len() + 1) > MAX_LCS_CELLS {
+    return None;
+  }
+
+  // lcs[i][j] is the length of the longest common subsequence of old_middle[i..] and new_middle[j..]
+  let mut lcs = vec![vec![0usize; new_middle.len() + 1]; old_middle.len() + 1];
+  for i in (0..old_middle.len()).rev() {
+    for j in (0..new_middle.len()).rev() {
+      lcs[i][j] = if old_middle[i] == new_middle[j] {
+        lcs[i + 1][j + 1] + 1
+      } else {
+        lcs[i + 1][j].max(lcs[i][j + 1])
+      };
+    }
+  }
+
+  let mut chunks: Vec<(DiffKind, String)> = vec![];
+  let mut push = |kind: DiffKind, token: &str| match chunks.last_mut() {
+    Some((last_kind, text)) if *last_kind == kind => text.push_str(token),
+    _ => chunks.push((kind, token.to_string())),
+  };
+
+  for token in &old[..prefix_len] {
+    push(DiffKind::Equal, token);
+  }
+
+  let (mut i, mut j) = (0, 0);
+  while i < old_middle.len() || j < new_middle.len() {
+    if i < old_middle.len() && j < new_middle.len() && old_middle[i] == new_middle[j] {
+      push(DiffKind::Equal, old_middle[i]);
+      i += 1;
+      j += 1;
+    } else if i < old_middle.len() && (j == new_middle.len() || lcs[i + 1][j] >= lcs[i][j + 1]) {
+      push(DiffKind::Delete, old_middle[i]);
+      i += 1;
+    } else {
+      push(DiffKind::Insert, new_middle[j]);
+      j += 1;
+    }
+  }
+
+  for token in &old[old.len() - suffix_len..] {
+    push(DiffKind::Equal, token);
+  }
+
+  Some(chunks)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // the text on one side of the diff, leaving out the chunks that only belong to the other side
+  fn side(chunks: &[(DiffKind, String)], skipped: DiffKind) -> String {
+    chunks
+      .iter()
+      .filter(|(kind, _)| *kind != skipped)
+      .map(|(_, text)| text.as_str())
+      .collect()
+  }
+
+  fn assert_joins_back(old: &str, new: &str) -> Vec<(DiffKind, String)> {
+    let chunks = diff_words(old, new).unwrap();
+    assert_eq!(side(&chunks, DiffKind::Insert), old);
+    assert_eq!(side(&chunks, DiffKind::Delete), new);
+    // adjacent chunks are always merged
+    assert!(chunks.windows(2).all(|x| x[0].0 != x[1].0));
+    chunks
+  }
+
+  #[test]
+  fn tokenize_joins_back() {
+    for text in [
+      "",
+      " ",
+      "word",
+      "  two  words\n",
+      "tab\tand\u{a0}nbsp",
+      "héllo wörld ",
+    ] {
+      assert_eq!(tokenize(text).concat(), text);
+    }
+  }
+
+  #[test]
+  fn tokenize_alternates_whitespace() {
+    assert_eq!(tokenize(" a  bc\n"), vec![" ", "a", "  ", "bc", "\n"]);
+    assert!(tokenize("").is_empty());
+  }
+
+  #[test]
+  fn diff_identical() {
+    assert_eq!(
+      assert_joins_back("the same text", "the same text"),
+      vec![(DiffKind::Equal, "the same text".to_string())]
+    );
+    assert!(assert_joins_back("", "").is_empty());
+  }
+
+  #[test]
+  fn diff_replaced_word() {
+    assert_eq!(
+      assert_joins_back("the quick fox", "the slow fox"),
+      vec![
+        (DiffKind::Equal, "the ".to_string()),
+        (DiffKind::Delete, "quick".to_string()),
+        (DiffKind::Insert, "slow".to_string()),
+        (DiffKind::Equal, " fox".to_string()),
+      ]
+    );
+  }
+
+  #[test]
+  fn diff_joins_back() {
+    let cases = [
+      ("", "all new"),
+      ("all old", ""),
+      ("a b c", "c b a"),
+      ("one two three", "one  two three four"),
+      ("repeated repeated words", "repeated words repeated"),
+      ("  leading", "leading  "),
+    ];
+    for (old, new) in cases {
+      assert_joins_back(old, new);
+    }
+  }
+
+  #[test]
+  fn diff_too_large() {
+    let old = (0..2000)
+      .map(|x| x.to_string())
+      .collect::<Vec<_>>()
+      .join(" ");
+    let new = (0..2000)
+      .map(|x| (x + 1).to_string())
+      .collect::<Vec<_>>()
+      .join(" ");
+    assert!(diff_words(&old, &new).is_none());
+    // a small edit to a long text only needs the part that changed
+    let edited = old.replacen("1000", "one thousand", 1);
+    assert_joins_back(&old, &edited);
+  }
+}
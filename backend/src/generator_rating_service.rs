@@ -0,0 +1,101 @@
+use super::db_types::*;
+use super::utils::current_time_millis;
+use tokio_postgres::GenericClient;
+
+impl From<tokio_postgres::row::Row> for GeneratorRating {
+  // select * from generator_rating order only, otherwise it will fail
+  fn from(row: tokio_postgres::Row) -> GeneratorRating {
+    GeneratorRating {
+      generator_rating_id: row.get("generator_rating_id"),
+      creation_time: row.get("creation_time"),
+      generator_id: row.get("generator_id"),
+      guess_id: row.get("guess_id"),
+      rating: row.get("rating"),
+      win_count: row.get("win_count"),
+      loss_count: row.get("loss_count"),
+    }
+  }
+}
+
+pub async fn add(
+  con: &mut impl GenericClient,
+  generator_id: i64,
+  guess_id: i64,
+  rating: f64,
+  win_count: i64,
+  loss_count: i64,
+) -> Result<GeneratorRating, tokio_postgres::Error> {
+  let creation_time = current_time_millis();
+
+  let generator_rating_id = con
+    .query_one(
+      "INSERT INTO
+       generator_rating(
+           creation_time,
+           generator_id,
+           guess_id,
+           rating,
+           win_count,
+           loss_count
+       )
+       VALUES ($1, $2, $3, $4, $5, $6)
+       RETURNING generator_rating_id
+      ",
+      &[
+        &creation_time,
+        &generator_id,
+        &guess_id,
+        &rating,
+        &win_count,
+        &loss_count,
+      ],
+    )
+    .await?
+    .get(0);
+
+  Ok(GeneratorRating {
+    generator_rating_id,
+    creation_time,
+    generator_id,
+    guess_id,
+    rating,
+    win_count,
+    loss_count,
+  })
+}
+
+pub async fn get_recent_by_generator_id(
+  con: &mut impl GenericClient,
+  generator_id: i64,
+) -> Result<Option<GeneratorRating>, tokio_postgres::Error> {
+  let result = con
+    .query_opt(
+      "SELECT * FROM recent_generator_rating WHERE generator_id=$1",
+      &[&generator_id],
+    )
+    .await?
+    .map(|x| x.into());
+
+  Ok(result)
+}
+
+// current ratings of every rated generator the user has registered, best first
+pub async fn get_recent_by_creator_user_ids(
+  con: &mut impl GenericClient,
+  creator_user_ids: &[i64],
+) -> Result<Vec<GeneratorRating>, tokio_postgres::Error> {
+  let results = con
+    .query(
+      "SELECT gr.* FROM recent_generator_rating gr
+       INNER JOIN generator ge ON ge.generator_id = gr.generator_id
+       WHERE ge.creator_user_id = ANY($1)
+       ORDER BY gr.rating DESC, gr.generator_id",
+      &[&creator_user_ids],
+    )
+    .await?
+    .into_iter()
+    .map(|x| x.into())
+    .collect();
+
+  Ok(results)
+}
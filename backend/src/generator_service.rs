@@ -0,0 +1,115 @@
+use super::db_types::*;
+use super::request;
+use super::utils::current_time_millis;
+use tokio_postgres::GenericClient;
+
+impl From<tokio_postgres::row::Row> for Generator {
+  // select * from generator order only, otherwise it will fail
+  fn from(row: tokio_postgres::Row) -> Generator {
+    Generator {
+      generator_id: row.get("generator_id"),
+      creation_time: row.get("creation_time"),
+      creator_user_id: row.get("creator_user_id"),
+      name: row.get("name"),
+      version: row.get("version"),
+      parameters: row.get("parameters"),
+    }
+  }
+}
+
+pub async fn add(
+  con: &mut impl GenericClient,
+  creator_user_id: i64,
+  name: String,
+  version: String,
+  parameters: serde_json::Value,
+) -> Result<Generator, tokio_postgres::Error> {
+  let creation_time = current_time_millis();
+
+  let generator_id = con
+    .query_one(
+      "INSERT INTO
+       generator(
+           creation_time,
+           creator_user_id,
+           name,
+           version,
+           parameters
+       )
+       VALUES ($1, $2, $3, $4, $5)
+       RETURNING generator_id
+      ",
+      &[
+        &creation_time,
+        &creator_user_id,
+        &name,
+        &version,
+        &parameters,
+      ],
+    )
+    .await?
+    .get(0);
+
+  Ok(Generator {
+    generator_id,
+    creation_time,
+    creator_user_id,
+    name,
+    version,
+    parameters,
+  })
+}
+
+pub async fn get_by_generator_id(
+  con: &mut impl GenericClient,
+  generator_id: i64,
+) -> Result<Option<Generator>, tokio_postgres::Error> {
+  let result = con
+    .query_opt(
+      "SELECT * FROM generator WHERE generator_id=$1",
+      &[&generator_id],
+    )
+    .await?
+    .map(|x| x.into());
+
+  Ok(result)
+}
+
+pub async fn query(
+  con: &mut impl GenericClient,
+  props: request::GeneratorViewProps,
+) -> Result<Vec<Generator>, tokio_postgres::Error> {
+  let sql = [
+    "SELECT ge.* FROM generator ge",
+    " WHERE 1 = 1",
+    " AND ($1::bigint[] IS NULL OR ge.generator_id = ANY($1))",
+    " AND ($2::bigint   IS NULL OR ge.creation_time >= $2)",
+    " AND ($3::bigint   IS NULL OR ge.creation_time <= $3)",
+    " AND ($4::bigint[] IS NULL OR ge.creator_user_id = ANY($4))",
+    " AND ($5::text[]   IS NULL OR ge.name = ANY($5))",
+    " AND ($6::text[]   IS NULL OR ge.version = ANY($6))",
+    " ORDER BY ge.generator_id",
+  ]
+  .join("\n");
+
+  let stmnt = con.prepare(&sql).await?;
+
+  let results = con
+    .query(
+      &stmnt,
+      &[
+        &props.generator_id,
+        &props.min_creation_time,
+        &props.max_creation_time,
+        &props.creator_user_id,
+        &props.name,
+        &props.version,
+      ],
+    )
+    .await?
+    .into_iter()
+    .map(|row| row.into())
+    .collect();
+
+  Ok(results)
+}
@@ -0,0 +1,65 @@
+use super::db_types::*;
+use tokio_postgres::GenericClient;
+
+impl From<tokio_postgres::row::Row> for GuessRecord {
+  fn from(row: tokio_postgres::Row) -> GuessRecord {
+    GuessRecord {
+      creator_user_id: row.get("creator_user_id"),
+      play_session_id: row.get("play_session_id"),
+      forced_choice: row.get("forced_choice"),
+      article_id: row.get("article_id"),
+      position: row.get("position"),
+      variants_shown: row.get("variants_shown"),
+      variant_chosen: row.get("variant_chosen"),
+      generator_id: row.get("generator_id"),
+      correct: row.get("correct"),
+      latency: row.get("latency"),
+      confidence: row.get("confidence"),
+    }
+  }
+}
+
+// every guess made on the given articles, oldest first
+pub async fn get_by_article_ids(
+  con: &mut impl GenericClient,
+  article_ids: &[i64],
+) -> Result<Vec<GuessRecord>, tokio_postgres::Error> {
+  let sql = [
+    "SELECT g.guess_id,",
+    "   coalesce(gc.creator_user_id, g.creator_user_id) creator_user_id,",
+    "   ps.play_session_id,",
+    "   ps.forced_choice,",
+    "   g.article_id,",
+    "   g.position,",
+    "   ARRAY(",
+    "     SELECT ase.variant FROM presentation_option po",
+    "     JOIN article_section ase ON ase.article_section_id = po.article_section_id",
+    "     WHERE po.presentation_id = g.presentation_id",
+    "     ORDER BY ase.variant",
+    "   ) variants_shown,",
+    "   chosen.variant variant_chosen,",
+    "   chosen.generator_id,",
+    "   g.correct,",
+    "   g.latency,",
+    "   g.confidence",
+    " FROM guess g",
+    " JOIN presentation p ON p.presentation_id = g.presentation_id",
+    " JOIN play_session ps ON ps.play_session_id = p.play_session_id",
+    " JOIN article_section chosen ON chosen.article_section_id = g.article_section_id",
+    " LEFT JOIN guest_claim gc ON gc.guest_id = -g.creator_user_id",
+    " WHERE g.article_id = ANY($1)",
+    " ORDER BY g.guess_id",
+  ]
+  .join("\n");
+
+  let stmnt = con.prepare(&sql).await?;
+
+  let results = con
+    .query(&stmnt, &[&article_ids])
+    .await?
+    .into_iter()
+    .map(|row| row.into())
+    .collect();
+
+  Ok(results)
+}
@@ -0,0 +1,212 @@
+use super::db_types::*;
+use super::utils::current_time_millis;
+use super::request;
+use tokio_postgres::GenericClient;
+
+impl From<tokio_postgres::row::Row> for Guess {
+  // select * from guess order only, otherwise it will fail
+  fn from(row: tokio_postgres::Row) -> Guess {
+    Guess {
+      guess_id: row.get("guess_id"),
+      creation_time: row.get("creation_time"),
+      creator_user_id: row.get("creator_user_id"),
+      article_id: row.get("article_id"),
+      position: row.get("position"),
+      presentation_id: row.get("presentation_id"),
+      presentation_option_id: row.get("presentation_option_id"),
+      article_section_id: row.get("article_section_id"),
+      correct: row.get("correct"),
+      points: row.get("points"),
+      latency: row.get("latency"),
+      confidence: row.get("confidence"),
+      brier_score: row.get("brier_score"),
+    }
+  }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub async fn add(
+  con: &mut impl GenericClient,
+  creator_user_id: i64,
+  article_id: i64,
+  position: i64,
+  presentation_id: i64,
+  presentation_option_id: i64,
+  article_section_id: i64,
+  correct: bool,
+  points: i64,
+  served_time: i64,
+  confidence: Option<f64>,
+  brier_score: Option<f64>,
+) -> Result<Guess, tokio_postgres::Error> {
+  let creation_time = current_time_millis();
+  let latency = creation_time - served_time;
+
+  let guess_id = con
+    .query_one(
+      "INSERT INTO
+       guess(
+           creation_time,
+           creator_user_id,
+           article_id,
+           position,
+           presentation_id,
+           presentation_option_id,
+           article_section_id,
+           correct,
+           points,
+           latency,
+           confidence,
+           brier_score
+       )
+       VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+       RETURNING guess_id
+      ",
+      &[
+        &creation_time,
+        &creator_user_id,
+        &article_id,
+        &position,
+        &presentation_id,
+        &presentation_option_id,
+        &article_section_id,
+        &correct,
+        &points,
+        &latency,
+        &confidence,
+        &brier_score,
+      ],
+    )
+    .await?
+    .get(0);
+
+  Ok(Guess {
+    guess_id,
+    creation_time,
+    creator_user_id,
+    article_id,
+    position,
+    presentation_id,
+    presentation_option_id,
+    article_section_id,
+    correct,
+    points,
+    latency,
+    confidence,
+    brier_score,
+  })
+}
+
+pub async fn get_by_guess_id(
+  con: &mut impl GenericClient,
+  guess_id: i64,
+) -> Result<Option<Guess>, tokio_postgres::Error> {
+  let result = con
+    .query_opt("SELECT * FROM guess WHERE guess_id=$1", &[&guess_id])
+    .await?
+    .map(|x| x.into());
+
+  Ok(result)
+}
+
+pub async fn get_by_presentation_id(
+  con: &mut impl GenericClient,
+  presentation_id: i64,
+) -> Result<Option<Guess>, tokio_postgres::Error> {
+  let result = con
+    .query_opt(
+      "SELECT * FROM guess WHERE presentation_id=$1",
+      &[&presentation_id],
+    )
+    .await?
+    .map(|x| x.into());
+
+  Ok(result)
+}
+
+// whether any of the given users have already guessed at this position of the article, in any session
+pub async fn has_guessed_position(
+  con: &mut impl GenericClient,
+  creator_user_ids: &[i64],
+  article_id: i64,
+  position: i64,
+) -> Result<bool, tokio_postgres::Error> {
+  let result = con
+    .query_one(
+      "SELECT EXISTS (
+         SELECT 1 FROM guess
+         WHERE creator_user_id = ANY($1) AND article_id=$2 AND position=$3
+       )",
+      &[&creator_user_ids, &article_id, &position],
+    )
+    .await?
+    .get(0);
+
+  Ok(result)
+}
+
+// every guess made during the session, in the order they were made
+pub async fn get_by_play_session_id(
+  con: &mut impl GenericClient,
+  play_session_id: i64,
+) -> Result<Vec<Guess>, tokio_postgres::Error> {
+  let results = con
+    .query(
+      "SELECT g.* FROM guess g
+       INNER JOIN presentation p ON g.presentation_id = p.presentation_id
+       WHERE p.play_session_id=$1
+       ORDER BY g.guess_id",
+      &[&play_session_id],
+    )
+    .await?
+    .into_iter()
+    .map(|x| x.into())
+    .collect();
+
+  Ok(results)
+}
+
+pub async fn query(
+  con: &mut impl GenericClient,
+  props: request::GuessViewProps,
+) -> Result<Vec<Guess>, tokio_postgres::Error> {
+  let sql = [
+    "SELECT gu.* FROM guess gu",
+    " WHERE 1 = 1",
+    " AND ($1::bigint[] IS NULL OR gu.guess_id = ANY($1))",
+    " AND ($2::bigint   IS NULL OR gu.creation_time >= $2)",
+    " AND ($3::bigint   IS NULL OR gu.creation_time <= $3)",
+    " AND ($4::bigint[] IS NULL OR gu.creator_user_id = ANY($4))",
+    " AND ($5::bigint[] IS NULL OR gu.article_id = ANY($5))",
+    " AND ($6::bigint[] IS NULL OR gu.position = ANY($6))",
+    " AND ($7::bigint[] IS NULL OR gu.presentation_id = ANY($7))",
+    " AND ($8::bigint[] IS NULL OR gu.article_section_id = ANY($8))",
+    " AND ($9::bool     IS NULL OR gu.correct = $9)",
+    " ORDER BY gu.guess_id",
+  ]
+  .join("\n");
+
+  let stmnt = con.prepare(&sql).await?;
+
+  let results = con
+    .query(
+      &stmnt,
+      &[
+        &props.guess_id,
+        &props.min_creation_time,
+        &props.max_creation_time,
+        &props.creator_user_id,
+        &props.article_id,
+        &props.position,
+        &props.presentation_id,
+        &props.article_section_id,
+        &props.correct,
+      ],
+    )
+    .await?
+    .into_iter()
+    .map(|row| row.into())
+    .collect();
+
+  Ok(results)
+}
@@ -0,0 +1,75 @@
+use super::db_types::*;
+use super::utils::current_time_millis;
+use tokio_postgres::GenericClient;
+
+impl From<tokio_postgres::row::Row> for GuestClaim {
+  // select * from guest_claim order only, otherwise it will fail
+  fn from(row: tokio_postgres::Row) -> GuestClaim {
+    GuestClaim {
+      guest_claim_id: row.get("guest_claim_id"),
+      creation_time: row.get("creation_time"),
+      creator_user_id: row.get("creator_user_id"),
+      guest_id: row.get("guest_id"),
+    }
+  }
+}
+
+pub async fn add(
+  con: &mut impl GenericClient,
+  creator_user_id: i64,
+  guest_id: i64,
+) -> Result<GuestClaim, tokio_postgres::Error> {
+  let creation_time = current_time_millis();
+
+  let guest_claim_id = con
+    .query_one(
+      "INSERT INTO
+       guest_claim(
+           creation_time,
+           creator_user_id,
+           guest_id
+       )
+       VALUES ($1, $2, $3)
+       RETURNING guest_claim_id
+      ",
+      &[&creation_time, &creator_user_id, &guest_id],
+    )
+    .await?
+    .get(0);
+
+  Ok(GuestClaim {
+    guest_claim_id,
+    creation_time,
+    creator_user_id,
+    guest_id,
+  })
+}
+
+pub async fn get_by_guest_id(
+  con: &mut impl GenericClient,
+  guest_id: i64,
+) -> Result<Option<GuestClaim>, tokio_postgres::Error> {
+  let result = con
+    .query_opt("SELECT * FROM guest_claim WHERE guest_id=$1", &[&guest_id])
+    .await?
+    .map(|x| x.into());
+
+  Ok(result)
+}
+
+pub async fn get_by_creator_user_id(
+  con: &mut impl GenericClient,
+  creator_user_id: i64,
+) -> Result<Vec<GuestClaim>, tokio_postgres::Error> {
+  let results = con
+    .query(
+      "SELECT * FROM guest_claim WHERE creator_user_id=$1",
+      &[&creator_user_id],
+    )
+    .await?
+    .into_iter()
+    .map(|x| x.into())
+    .collect();
+
+  Ok(results)
+}
@@ -0,0 +1,48 @@
+use super::db_types::*;
+use super::utils::current_time_millis;
+use tokio_postgres::GenericClient;
+
+impl From<tokio_postgres::row::Row> for Guest {
+  // select * from guest order only, otherwise it will fail
+  fn from(row: tokio_postgres::Row) -> Guest {
+    Guest {
+      guest_id: row.get("guest_id"),
+      creation_time: row.get("creation_time"),
+    }
+  }
+}
+
+pub async fn add(con: &mut impl GenericClient) -> Result<Guest, tokio_postgres::Error> {
+  let creation_time = current_time_millis();
+
+  let guest_id = con
+    .query_one(
+      "INSERT INTO
+       guest(
+           creation_time
+       )
+       VALUES ($1)
+       RETURNING guest_id
+      ",
+      &[&creation_time],
+    )
+    .await?
+    .get(0);
+
+  Ok(Guest {
+    guest_id,
+    creation_time,
+  })
+}
+
+pub async fn get_by_guest_id(
+  con: &mut impl GenericClient,
+  guest_id: i64,
+) -> Result<Option<Guest>, tokio_postgres::Error> {
+  let result = con
+    .query_opt("SELECT * FROM guest WHERE guest_id=$1", &[&guest_id])
+    .await?
+    .map(|x| x.into());
+
+  Ok(result)
+}
@@ -0,0 +1,113 @@
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+type HmacSha256 = Hmac<Sha256>;
+
+// guest tokens are passed in place of an api key, so they need to be told apart from them
+static GUEST_TOKEN_PREFIX: &str = "guest:";
+
+fn mac(secret: &str, guest_id: i64) -> HmacSha256 {
+  let mut mac =
+    HmacSha256::new_from_slice(secret.as_bytes()).expect("hmac accepts keys of any length");
+  mac.update(guest_id.to_string().as_bytes());
+  mac
+}
+
+pub fn is_guest_token(token: &str) -> bool {
+  token.starts_with(GUEST_TOKEN_PREFIX)
+}
+
+// token is of the form guest:<guest_id>:<hex encoded hmac of guest_id>
+pub fn sign(secret: &str, guest_id: i64) -> String {
+  let signature = hex::encode(mac(secret, guest_id).finalize().into_bytes());
+  format!("{}{}:{}", GUEST_TOKEN_PREFIX, guest_id, signature)
+}
+
+// returns the guest_id if the token was signed by us
+pub fn verify(secret: &str, token: &str) -> Option<i64> {
+  let (guest_id, signature) = token.strip_prefix(GUEST_TOKEN_PREFIX)?.split_once(':')?;
+  // only the form sign writes is accepted, so every guest has exactly one token
+  let guest_id = guest_id
+    .parse()
+    .ok()
+    .filter(|x: &i64| *x >= 0 && x.to_string() == guest_id)?;
+  let signature = hex::decode(signature).ok()?;
+
+  mac(secret, guest_id)
+    .verify_slice(&signature)
+    .ok()
+    .map(|_| guest_id)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  static SECRET: &str = "test-secret";
+
+  #[test]
+  fn round_trip() {
+    for guest_id in [0, 1, 42, i64::MAX] {
+      let token = sign(SECRET, guest_id);
+      assert!(is_guest_token(&token));
+      assert_eq!(verify(SECRET, &token), Some(guest_id));
+    }
+  }
+
+  #[test]
+  fn api_keys_are_not_guest_tokens() {
+    assert!(!is_guest_token("0123456789abcdef"));
+    assert_eq!(verify(SECRET, "0123456789abcdef"), None);
+  }
+
+  #[test]
+  fn tampered_id() {
+    let signature = sign(SECRET, 42).rsplit_once(':').unwrap().1.to_string();
+    assert_eq!(verify(SECRET, &format!("guest:43:{}", signature)), None);
+    // the same number written differently isn't the token that was signed
+    assert_eq!(verify(SECRET, &format!("guest:+42:{}", signature)), None);
+    assert_eq!(verify(SECRET, &format!("guest:042:{}", signature)), None);
+  }
+
+  #[test]
+  fn tampered_mac() {
+    let token = sign(SECRET, 42);
+    let last = token.chars().last().unwrap();
+    let flipped = if last == '0' { '1' } else { '0' };
+    let tampered = format!("{}{}", &token[..token.len() - 1], flipped);
+    assert_eq!(verify(SECRET, &tampered), None);
+    // a truncated mac doesn't verify either
+    assert_eq!(verify(SECRET, &token[..token.len() - 2]), None);
+  }
+
+  #[test]
+  fn wrong_secret() {
+    assert_eq!(verify("other-secret", &sign(SECRET, 42)), None);
+  }
+
+  #[test]
+  fn malformed() {
+    let signature = sign(SECRET, 42).rsplit_once(':').unwrap().1.to_string();
+    for token in [
+      "",
+      "guest:",
+      "guest:42",
+      "guest:42:",
+      "guest::",
+      "guest:abc:00",
+      "guest:42:not-hex",
+      "guest:42:0",
+      "42:00",
+    ] {
+      assert_eq!(verify(SECRET, token), None, "{}", token);
+    }
+    assert_eq!(verify(SECRET, &format!("42:{}", signature)), None);
+    assert_eq!(verify(SECRET, &format!("guest:{}", signature)), None);
+    assert_eq!(verify(SECRET, &format!("guest:42:{}:", signature)), None);
+  }
+
+  #[test]
+  fn negative_id() {
+    assert_eq!(verify(SECRET, &sign(SECRET, -1)), None);
+  }
+}
@@ -7,16 +7,50 @@ use super::request;
 use super::response;
 
 use super::db_types::*;
+use super::diff;
+use super::stats;
 use super::utils;
 
+use super::annotation_service;
 use super::article_data_service;
 use super::article_section_service;
+use super::article_section_stats_service;
 use super::article_service;
+use super::article_tag_service;
+use super::calibration_service;
+use super::daily_challenge_service;
+use super::generator_rating_service;
+use super::generator_service;
+use super::guess_service;
+use super::guest_claim_service;
+use super::guest_service;
+use super::guest_token;
+use super::latency_stats_service;
+use super::leaderboard_service;
+use super::play_session_data_service;
+use super::play_session_service;
+use super::position_difficulty_service;
+use super::presentation_option_service;
+use super::presentation_service;
+use super::ranking_service;
+use super::user_rating_service;
+
+use rand::seq::SliceRandom;
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use tokio_postgres::GenericClient;
 
 use std::error::Error;
 
 use super::Config;
 
+static LEADERBOARD_SIZE: i64 = 100;
+static CALIBRATION_BIN_COUNT: i64 = 10;
+static DAY_MILLIS: i64 = 24 * 60 * 60 * 1000;
+static WEEK_MILLIS: i64 = 7 * DAY_MILLIS;
+// revisions by the same user less than this far apart belong to the same edit
+static EDIT_GAP_MILLIS: i64 = 10 * 60 * 1000;
+
 fn report_postgres_err(e: tokio_postgres::Error) -> response::AppError {
     utils::log(utils::Event {
         msg: e.to_string(),
@@ -50,6 +84,19 @@ fn report_auth_err(e: AuthError) -> response::AppError {
     }
 }
 
+async fn fill_guest_claim(
+    _con: &mut tokio_postgres::Client,
+    guest_claim: GuestClaim,
+) -> Result<response::GuestClaim, response::AppError> {
+    Ok(response::GuestClaim {
+        guest_claim_id: guest_claim.guest_claim_id,
+        creation_time: guest_claim.creation_time,
+        creator_user_id: guest_claim.creator_user_id,
+        guest_id: guest_claim.guest_id,
+        guest_user_id: guest_user_id(guest_claim.guest_id),
+    })
+}
+
 async fn fill_article(
     _con: &mut tokio_postgres::Client,
     article: Article,
@@ -81,6 +128,84 @@ async fn fill_article_data(
     })
 }
 
+fn fill_bibliography(bibliography: Bibliography) -> response::Bibliography {
+    response::Bibliography {
+        authors: bibliography.authors,
+        venue: bibliography.venue,
+        year: bibliography.year,
+        doi: bibliography.doi,
+        source_url: bibliography.source_url,
+        abstract_text: bibliography.abstract_text,
+        license: bibliography.license,
+    }
+}
+
+fn bibliography_from_props(bibliography: request::Bibliography) -> Bibliography {
+    Bibliography {
+        authors: bibliography.authors.unwrap_or_default(),
+        venue: bibliography.venue,
+        year: bibliography.year,
+        doi: bibliography.doi,
+        source_url: bibliography.source_url,
+        abstract_text: bibliography.abstract_text,
+        license: bibliography.license,
+    }
+}
+
+async fn fill_article_tag(
+    con: &mut tokio_postgres::Client,
+    article_tag: ArticleTag,
+) -> Result<response::ArticleTag, response::AppError> {
+    let article = article_service::get_by_article_id(con, article_tag.article_id)
+        .await
+        .map_err(report_postgres_err)?
+        .ok_or(response::AppError::ArticleNonexistent)?;
+
+    Ok(response::ArticleTag {
+        article_tag_id: article_tag.article_tag_id,
+        creation_time: article_tag.creation_time,
+        creator_user_id: article_tag.creator_user_id,
+        article: fill_article(con, article).await?,
+        kind: article_tag.kind,
+        name: article_tag.name,
+        active: article_tag.active,
+    })
+}
+
+async fn fill_generator(
+    _con: &mut tokio_postgres::Client,
+    generator: Generator,
+) -> Result<response::Generator, response::AppError> {
+    Ok(response::Generator {
+        generator_id: generator.generator_id,
+        creation_time: generator.creation_time,
+        creator_user_id: generator.creator_user_id,
+        name: generator.name,
+        version: generator.version,
+        parameters: generator.parameters,
+    })
+}
+
+async fn fill_generator_rating(
+    con: &mut tokio_postgres::Client,
+    generator_rating: GeneratorRating,
+) -> Result<response::GeneratorRating, response::AppError> {
+    let generator = generator_service::get_by_generator_id(con, generator_rating.generator_id)
+        .await
+        .map_err(report_postgres_err)?
+        .ok_or(response::AppError::GeneratorNonexistent)?;
+
+    Ok(response::GeneratorRating {
+        generator_rating_id: generator_rating.generator_rating_id,
+        creation_time: generator_rating.creation_time,
+        generator: fill_generator(con, generator).await?,
+        guess_id: generator_rating.guess_id,
+        rating: generator_rating.rating,
+        win_count: generator_rating.win_count,
+        loss_count: generator_rating.loss_count,
+    })
+}
+
 async fn fill_article_section(
     con: &mut tokio_postgres::Client,
     article_section: ArticleSection,
@@ -90,258 +215,2809 @@ async fn fill_article_section(
         .map_err(report_postgres_err)?
         .ok_or(response::AppError::ArticleNonexistent)?;
 
-    Ok(response::ArticleSection {
-        article_section_id: article_section.article_section_id,
-        creation_time: article_section.creation_time,
-        creator_user_id: article_section.creator_user_id,
-        article: fill_article(con, article).await?,
-        position: article_section.position,
-        variant: article_section.variant,
-        section_text: article_section.section_text,
-        active: article_section.active,
-    })
-}
+    let generator = match article_section.generator_id {
+        Some(generator_id) => {
+            let generator = generator_service::get_by_generator_id(con, generator_id)
+                .await
+                .map_err(report_postgres_err)?
+                .ok_or(response::AppError::GeneratorNonexistent)?;
+            Some(fill_generator(con, generator).await?)
+        }
+        None => None,
+    };
+
+    Ok(response::ArticleSection {
+        article_section_id: article_section.article_section_id,
+        creation_time: article_section.creation_time,
+        creator_user_id: article_section.creator_user_id,
+        article: fill_article(con, article).await?,
+        position: article_section.position,
+        variant: article_section.variant,
+        section_text: article_section.section_text,
+        generator,
+        active: article_section.active,
+    })
+}
+
+async fn fill_article_section_stats(
+    con: &mut tokio_postgres::Client,
+    article_section_stats: ArticleSectionStats,
+) -> Result<response::ArticleSectionStats, response::AppError> {
+    let article = article_service::get_by_article_id(con, article_section_stats.article_id)
+        .await
+        .map_err(report_postgres_err)?
+        .ok_or(response::AppError::ArticleNonexistent)?;
+
+    let generator = match article_section_stats.generator_id {
+        Some(generator_id) => {
+            let generator = generator_service::get_by_generator_id(con, generator_id)
+                .await
+                .map_err(report_postgres_err)?
+                .ok_or(response::AppError::GeneratorNonexistent)?;
+            Some(fill_generator(con, generator).await?)
+        }
+        None => None,
+    };
+
+    let (pick_rate_low, pick_rate_high) = stats::wilson_interval(
+        article_section_stats.pick_count,
+        article_section_stats.shown_count,
+    );
+
+    Ok(response::ArticleSectionStats {
+        article: fill_article(con, article).await?,
+        position: article_section_stats.position,
+        variant: article_section_stats.variant,
+        generator,
+        shown_count: article_section_stats.shown_count,
+        pick_count: article_section_stats.pick_count,
+        pick_rate: article_section_stats.pick_count as f64
+            / article_section_stats.shown_count as f64,
+        pick_rate_low,
+        pick_rate_high,
+    })
+}
+
+async fn fill_play_session(
+    con: &mut tokio_postgres::Client,
+    play_session: PlaySession,
+) -> Result<response::PlaySession, response::AppError> {
+    let article = article_service::get_by_article_id(con, play_session.article_id)
+        .await
+        .map_err(report_postgres_err)?
+        .ok_or(response::AppError::ArticleNonexistent)?;
+
+    Ok(response::PlaySession {
+        play_session_id: play_session.play_session_id,
+        creation_time: play_session.creation_time,
+        creator_user_id: play_session.creator_user_id,
+        article: fill_article(con, article).await?,
+        daily_challenge_id: play_session.daily_challenge_id,
+        forced_choice: play_session.forced_choice,
+        scored: play_session.scored,
+    })
+}
+
+async fn fill_play_session_data(
+    con: &mut tokio_postgres::Client,
+    play_session_data: PlaySessionData,
+) -> Result<response::PlaySessionData, response::AppError> {
+    let play_session =
+        play_session_service::get_by_play_session_id(con, play_session_data.play_session_id)
+            .await
+            .map_err(report_postgres_err)?
+            .ok_or(response::AppError::PlaySessionNonexistent)?;
+
+    Ok(response::PlaySessionData {
+        play_session_data_id: play_session_data.play_session_data_id,
+        creation_time: play_session_data.creation_time,
+        creator_user_id: play_session_data.creator_user_id,
+        play_session: fill_play_session(con, play_session).await?,
+        position: play_session_data.position,
+        finish_time: play_session_data.finish_time,
+        abandon_time: play_session_data.abandon_time,
+    })
+}
+
+async fn fill_position_difficulty(
+    con: &mut tokio_postgres::Client,
+    position_difficulty: PositionDifficulty,
+) -> Result<response::PositionDifficulty, response::AppError> {
+    let article = article_service::get_by_article_id(con, position_difficulty.article_id)
+        .await
+        .map_err(report_postgres_err)?
+        .ok_or(response::AppError::ArticleNonexistent)?;
+
+    Ok(response::PositionDifficulty {
+        position_difficulty_id: position_difficulty.position_difficulty_id,
+        creation_time: position_difficulty.creation_time,
+        article: fill_article(con, article).await?,
+        position: position_difficulty.position,
+        guess_id: position_difficulty.guess_id,
+        rating: position_difficulty.rating,
+        guess_count: position_difficulty.guess_count,
+    })
+}
+
+async fn fill_presentation(
+    con: &mut tokio_postgres::Client,
+    presentation: Presentation,
+) -> Result<response::Presentation, response::AppError> {
+    let article = article_service::get_by_article_id(con, presentation.article_id)
+        .await
+        .map_err(report_postgres_err)?
+        .ok_or(response::AppError::ArticleNonexistent)?;
+
+    let presentation_options =
+        presentation_option_service::get_by_presentation_id(con, presentation.presentation_id)
+            .await
+            .map_err(report_postgres_err)?;
+
+    // every earlier position has already been answered in this session, so it's safe to reveal
+    let context = article_section_service::get_recent_active_true_before_position(
+        con,
+        presentation.article_id,
+        presentation.position,
+    )
+    .await
+    .map_err(report_postgres_err)?
+    .into_iter()
+    .map(|x| x.section_text)
+    .collect();
+
+    let mut options = vec![];
+    for presentation_option in presentation_options {
+        let article_section = article_section_service::get_by_article_section_id(
+            con,
+            &presentation_option.article_section_id,
+        )
+        .await
+        .map_err(report_postgres_err)?
+        .ok_or(response::AppError::ArticleSectionNonexistent)?;
+
+        options.push(response::PresentationOption {
+            presentation_option_id: presentation_option.presentation_option_id,
+            section_text: article_section.section_text,
+        });
+    }
+
+    let difficulty = match position_difficulty_service::get_recent_by_position(
+        con,
+        presentation.article_id,
+        presentation.position,
+    )
+    .await
+    .map_err(report_postgres_err)?
+    {
+        Some(position_difficulty) => {
+            Some(fill_position_difficulty(con, position_difficulty).await?)
+        }
+        None => None,
+    };
+
+    Ok(response::Presentation {
+        presentation_id: presentation.presentation_id,
+        creation_time: presentation.creation_time,
+        creator_user_id: presentation.creator_user_id,
+        play_session_id: presentation.play_session_id,
+        article: fill_article(con, article).await?,
+        position: presentation.position,
+        context,
+        options,
+        difficulty,
+    })
+}
+
+// one square per position that had something to choose between
+// positions with a single option are always correct and worth nothing, so they're left out of results
+fn daily_challenge_scored_guesses(guesses: Vec<Guess>) -> Vec<Guess> {
+    guesses
+        .into_iter()
+        .filter(|x| !(x.correct && x.points == 0))
+        .collect()
+}
+
+fn daily_challenge_share_text(daily_challenge: &DailyChallenge, guesses: &[Guess]) -> String {
+    let correct_count = guesses.iter().filter(|x| x.correct).count();
+    let squares: String = guesses
+        .iter()
+        .map(|x| if x.correct { '🟩' } else { '🟥' })
+        .collect();
+
+    format!(
+        "Critica Daily #{} {}/{}\n{}",
+        daily_challenge.daily_challenge_id,
+        correct_count,
+        guesses.len(),
+        squares
+    )
+}
+
+async fn fill_daily_challenge(
+    con: &mut tokio_postgres::Client,
+    daily_challenge: DailyChallenge,
+    attempt: Option<PlaySession>,
+) -> Result<response::DailyChallenge, response::AppError> {
+    let article_data =
+        article_data_service::get_recent_by_article_id(con, daily_challenge.article_id)
+            .await
+            .map_err(report_postgres_err)?
+            .ok_or(response::AppError::ArticleNonexistent)?;
+
+    let (play_session_data, result) = match attempt {
+        Some(play_session) => {
+            let play_session_data = play_session_data_service::get_recent_by_play_session_id(
+                con,
+                play_session.play_session_id,
+            )
+            .await
+            .map_err(report_postgres_err)?
+            .ok_or(response::AppError::PlaySessionNonexistent)?;
+
+            let result = if play_session_data.finish_time.is_some() {
+                let guesses = daily_challenge_scored_guesses(
+                    guess_service::get_by_play_session_id(con, play_session.play_session_id)
+                        .await
+                        .map_err(report_postgres_err)?,
+                );
+
+                Some(response::DailyChallengeResult {
+                    correct_count: guesses.iter().filter(|x| x.correct).count() as i64,
+                    guess_count: guesses.len() as i64,
+                    points: guesses.iter().map(|x| x.points).sum(),
+                    share_text: daily_challenge_share_text(&daily_challenge, &guesses),
+                })
+            } else {
+                None
+            };
+
+            (
+                Some(fill_play_session_data(con, play_session_data).await?),
+                result,
+            )
+        }
+        None => (None, None),
+    };
+
+    Ok(response::DailyChallenge {
+        daily_challenge_id: daily_challenge.daily_challenge_id,
+        creation_time: daily_challenge.creation_time,
+        day: daily_challenge.day,
+        article_data: fill_article_data(con, article_data).await?,
+        play_session_data,
+        result,
+    })
+}
+
+fn fill_diff(old: &str, new: &str) -> Result<Vec<response::DiffChunk>, response::AppError> {
+    Ok(diff::diff_words(old, new)
+        .ok_or(response::AppError::DiffTooLarge)?
+        .into_iter()
+        .map(|(kind, text)| response::DiffChunk {
+            kind: match kind {
+                diff::DiffKind::Equal => response::DiffKind::Equal,
+                diff::DiffKind::Insert => response::DiffKind::Insert,
+                diff::DiffKind::Delete => response::DiffKind::Delete,
+            },
+            text,
+        })
+        .collect())
+}
+
+enum ArticleRevision {
+    Data(ArticleData),
+    Section(ArticleSection),
+}
+
+impl ArticleRevision {
+    fn creation_time(&self) -> i64 {
+        match self {
+            ArticleRevision::Data(x) => x.creation_time,
+            ArticleRevision::Section(x) => x.creation_time,
+        }
+    }
+
+    fn creator_user_id(&self) -> i64 {
+        match self {
+            ArticleRevision::Data(x) => x.creator_user_id,
+            ArticleRevision::Section(x) => x.creator_user_id,
+        }
+    }
+}
+
+// none if there are no revisions, since an edit that changed nothing has no author or time
+async fn fill_article_edit(
+    con: &mut tokio_postgres::Client,
+    revisions: Vec<ArticleRevision>,
+) -> Result<Option<response::ArticleEdit>, response::AppError> {
+    let (first, last) = match (revisions.first(), revisions.last()) {
+        (Some(first), Some(last)) => (first, last),
+        _ => return Ok(None),
+    };
+
+    let mut article_edit = response::ArticleEdit {
+        start_time: first.creation_time(),
+        end_time: last.creation_time(),
+        creator_user_id: first.creator_user_id(),
+        article_data: vec![],
+        article_sections: vec![],
+    };
+
+    for revision in revisions {
+        match revision {
+            ArticleRevision::Data(x) => article_edit
+                .article_data
+                .push(fill_article_data(con, x).await?),
+            ArticleRevision::Section(x) => article_edit
+                .article_sections
+                .push(fill_article_section(con, x).await?),
+        }
+    }
+
+    Ok(Some(article_edit))
+}
+
+fn fill_calibration_bin(calibration_bin: CalibrationBin) -> response::CalibrationBin {
+    response::CalibrationBin {
+        min_confidence: calibration_bin.min_confidence,
+        max_confidence: calibration_bin.max_confidence,
+        guess_count: calibration_bin.guess_count,
+        correct_count: calibration_bin.correct_count,
+        accuracy: calibration_bin.correct_count as f64 / calibration_bin.guess_count as f64,
+        mean_confidence: calibration_bin.mean_confidence,
+        mean_brier_score: calibration_bin.mean_brier_score,
+    }
+}
+
+fn fill_latency_stats(latency_stats: &LatencyStats) -> response::LatencyStats {
+    response::LatencyStats {
+        guess_count: latency_stats.guess_count,
+        mean_latency: latency_stats.mean_latency,
+        median_latency: latency_stats.median_latency,
+        p90_latency: latency_stats.p90_latency,
+    }
+}
+
+async fn fill_annotation(
+    con: &mut tokio_postgres::Client,
+    annotation: Annotation,
+) -> Result<response::Annotation, response::AppError> {
+    let article_section =
+        article_section_service::get_by_article_section_id(con, &annotation.article_section_id)
+            .await
+            .map_err(report_postgres_err)?
+            .ok_or(response::AppError::ArticleSectionNonexistent)?;
+
+    Ok(response::Annotation {
+        annotation_id: annotation.annotation_id,
+        creation_time: annotation.creation_time,
+        creator_user_id: annotation.creator_user_id,
+        guess_id: annotation.guess_id,
+        article_section: fill_article_section(con, article_section).await?,
+        start_offset: annotation.start_offset,
+        end_offset: annotation.end_offset,
+        reason: annotation.reason,
+    })
+}
+
+// sweeps over where annotations start and end, emitting a span whenever the coverage changes
+fn annotation_heatmap_spans(annotations: &[Annotation]) -> Vec<response::AnnotationHeatmapSpan> {
+    let mut deltas = BTreeMap::new();
+    for annotation in annotations {
+        *deltas.entry(annotation.start_offset).or_insert(0) += 1;
+        *deltas.entry(annotation.end_offset).or_insert(0) -= 1;
+    }
+
+    let mut spans = vec![];
+    let mut count = 0;
+    let mut start_offset = 0;
+    for (offset, delta) in deltas {
+        if count > 0 && offset > start_offset {
+            spans.push(response::AnnotationHeatmapSpan {
+                start_offset,
+                end_offset: offset,
+                count,
+            });
+        }
+        count += delta;
+        start_offset = offset;
+    }
+
+    spans
+}
+
+async fn fill_ranking(
+    con: &mut tokio_postgres::Client,
+    ranking: Ranking,
+) -> Result<response::Ranking, response::AppError> {
+    let guess = guess_service::get_by_guess_id(con, ranking.guess_id)
+        .await
+        .map_err(report_postgres_err)?
+        .ok_or(response::AppError::InternalServerError)?;
+
+    Ok(response::Ranking {
+        ranking_id: ranking.ranking_id,
+        creation_time: ranking.creation_time,
+        creator_user_id: ranking.creator_user_id,
+        presentation_id: ranking.presentation_id,
+        presentation_option_ids: ranking.presentation_option_ids,
+        true_rank: ranking.true_rank,
+        points: ranking.points,
+        guess: fill_guess(con, guess).await?,
+    })
+}
+
+async fn fill_user_rating(
+    _con: &mut tokio_postgres::Client,
+    user_rating: UserRating,
+) -> Result<response::UserRating, response::AppError> {
+    Ok(response::UserRating {
+        user_rating_id: user_rating.user_rating_id,
+        creation_time: user_rating.creation_time,
+        creator_user_id: user_rating.creator_user_id,
+        guess_id: user_rating.guess_id,
+        rating: user_rating.rating,
+        guess_count: user_rating.guess_count,
+    })
+}
+
+async fn fill_guess(
+    con: &mut tokio_postgres::Client,
+    guess: Guess,
+) -> Result<response::Guess, response::AppError> {
+    let article = article_service::get_by_article_id(con, guess.article_id)
+        .await
+        .map_err(report_postgres_err)?
+        .ok_or(response::AppError::ArticleNonexistent)?;
+
+    // now that the guess has been made, we can reveal which option was the true completion
+    let presentation_options =
+        presentation_option_service::get_by_presentation_id(con, guess.presentation_id)
+            .await
+            .map_err(report_postgres_err)?;
+
+    let mut solution_presentation_option_id = None;
+    for presentation_option in presentation_options {
+        let article_section = article_section_service::get_by_article_section_id(
+            con,
+            &presentation_option.article_section_id,
+        )
+        .await
+        .map_err(report_postgres_err)?
+        .ok_or(response::AppError::ArticleSectionNonexistent)?;
+
+        if article_section.variant == 0 {
+            solution_presentation_option_id = Some(presentation_option.presentation_option_id);
+        }
+    }
+
+    let ranking_id = ranking_service::get_by_guess_id(con, guess.guess_id)
+        .await
+        .map_err(report_postgres_err)?
+        .map(|x| x.ranking_id);
+
+    Ok(response::Guess {
+        guess_id: guess.guess_id,
+        creation_time: guess.creation_time,
+        creator_user_id: guess.creator_user_id,
+        article: fill_article(con, article).await?,
+        position: guess.position,
+        presentation_id: guess.presentation_id,
+        presentation_option_id: guess.presentation_option_id,
+        article_section_id: guess.article_section_id,
+        correct: guess.correct,
+        points: guess.points,
+        latency: guess.latency,
+        confidence: guess.confidence,
+        brier_score: guess.brier_score,
+        solution_presentation_option_id,
+        ranking_id,
+    })
+}
+
+// whoever is making a request: either an auth service user, or a guest we issued a token to
+struct Identity {
+    user_id: i64,
+    // the user ids of the guests this user has claimed
+    claimed_user_ids: Vec<i64>,
+}
+
+impl Identity {
+    // whether something created by creator_user_id belongs to this identity
+    fn owns(&self, creator_user_id: i64) -> bool {
+        self.user_id == creator_user_id || self.claimed_user_ids.contains(&creator_user_id)
+    }
+
+    fn user_ids(&self) -> Vec<i64> {
+        let mut user_ids = vec![self.user_id];
+        user_ids.extend(&self.claimed_user_ids);
+        user_ids
+    }
+}
+
+// a guest's user id is the negation of its guest_id, so it never collides with an auth service user
+fn guest_user_id(guest_id: i64) -> i64 {
+    -guest_id
+}
+
+fn is_guest_user_id(user_id: i64) -> bool {
+    user_id < 0
+}
+
+pub async fn get_user_if_api_key_valid(
+    auth_service: &auth_service_api::client::AuthService,
+    api_key: String,
+) -> Result<User, response::AppError> {
+    auth_service
+        .get_user_by_api_key_if_valid(api_key)
+        .await
+        .map_err(report_auth_err)
+}
+
+// guests are only enabled when the service was started with a secret to sign their tokens with
+fn get_guest_token_secret(config: &Config) -> Result<&str, response::AppError> {
+    config
+        .guest_token_secret
+        .as_deref()
+        .ok_or(response::AppError::GuestsDisabled)
+}
+
+// guests pass their guest token in place of an api key
+async fn get_identity_if_api_key_valid(
+    config: &Config,
+    db: &Db,
+    auth_service: &AuthService,
+    api_key: String,
+) -> Result<Identity, response::AppError> {
+    if guest_token::is_guest_token(&api_key) {
+        let guest_id = guest_token::verify(get_guest_token_secret(config)?, &api_key)
+            .ok_or(response::AppError::Unauthorized)?;
+        return Ok(Identity {
+            user_id: guest_user_id(guest_id),
+            claimed_user_ids: vec![],
+        });
+    }
+
+    let user = get_user_if_api_key_valid(auth_service, api_key).await?;
+
+    let con = &mut *db.lock().await;
+    let claimed_user_ids = guest_claim_service::get_by_creator_user_id(con, user.user_id)
+        .await
+        .map_err(report_postgres_err)?
+        .into_iter()
+        .map(|x| guest_user_id(x.guest_id))
+        .collect();
+
+    Ok(Identity {
+        user_id: user.user_id,
+        claimed_user_ids,
+    })
+}
+
+// returns the session if it belongs to the user and hasn't been finished or abandoned yet
+async fn get_open_play_session(
+    con: &mut impl GenericClient,
+    user: &Identity,
+    play_session_id: i64,
+) -> Result<(PlaySession, PlaySessionData), response::AppError> {
+    let play_session = play_session_service::get_by_play_session_id(con, play_session_id)
+        .await
+        .map_err(report_postgres_err)?
+        .ok_or(response::AppError::PlaySessionNonexistent)?;
+    // validate session is owned by correct user
+    if !user.owns(play_session.creator_user_id) {
+        return Err(response::AppError::PlaySessionNonexistent);
+    }
+
+    let play_session_data =
+        play_session_data_service::get_recent_by_play_session_id(con, play_session_id)
+            .await
+            .map_err(report_postgres_err)?
+            .ok_or(response::AppError::PlaySessionNonexistent)?;
+    // validate session is still open
+    if play_session_data.finish_time.is_some() || play_session_data.abandon_time.is_some() {
+        return Err(response::AppError::PlaySessionClosed);
+    }
+
+    Ok((play_session, play_session_data))
+}
+
+// today's daily challenge can only be played through daily/play until the player has finished their attempt,
+// otherwise they could learn its answers in an ordinary session first
+async fn ensure_not_unfinished_daily_challenge(
+    con: &mut impl GenericClient,
+    user: &Identity,
+    article_id: i64,
+) -> Result<(), response::AppError> {
+    let day = utils::current_time_millis() / DAY_MILLIS;
+
+    let daily_challenge = match daily_challenge_service::get_by_day(con, day)
+        .await
+        .map_err(report_postgres_err)?
+    {
+        Some(daily_challenge) if daily_challenge.article_id == article_id => daily_challenge,
+        _ => return Ok(()),
+    };
+
+    let attempt = play_session_service::get_by_daily_challenge_id(
+        con,
+        &user.user_ids(),
+        daily_challenge.daily_challenge_id,
+    )
+    .await
+    .map_err(report_postgres_err)?;
+
+    let finished = match attempt {
+        Some(play_session) => play_session_data_service::get_recent_by_play_session_id(
+            con,
+            play_session.play_session_id,
+        )
+        .await
+        .map_err(report_postgres_err)?
+        .is_some_and(|x| x.finish_time.is_some()),
+        None => false,
+    };
+
+    if !finished {
+        return Err(response::AppError::DailyChallengeNotFinished);
+    }
+
+    Ok(())
+}
+
+// today's challenge, picking and recording it if this is the first request of the day
+async fn get_or_create_daily_challenge(
+    con: &mut impl GenericClient,
+) -> Result<DailyChallenge, response::AppError> {
+    let day = utils::current_time_millis() / DAY_MILLIS;
+
+    if let Some(daily_challenge) = daily_challenge_service::get_by_day(con, day)
+        .await
+        .map_err(report_postgres_err)?
+    {
+        return Ok(daily_challenge);
+    }
+
+    // prefer articles that haven't been a daily challenge before
+    let mut article_ids = daily_challenge_service::get_candidate_article_ids(con, false)
+        .await
+        .map_err(report_postgres_err)?;
+    if article_ids.is_empty() {
+        article_ids = daily_challenge_service::get_candidate_article_ids(con, true)
+            .await
+            .map_err(report_postgres_err)?;
+    }
+
+    // the pick only depends on the day and the candidates, so it's the same for everyone
+    // splitmix64 finalizer, so that consecutive days don't walk through the articles in order
+    let mut hash = day as u64;
+    hash = (hash ^ (hash >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    hash = (hash ^ (hash >> 27)).wrapping_mul(0x94d049bb133111eb);
+    hash ^= hash >> 31;
+
+    let article_id = *article_ids
+        .get((hash % article_ids.len().max(1) as u64) as usize)
+        .ok_or(response::AppError::DailyChallengeNonexistent)?;
+
+    daily_challenge_service::add(con, day, article_id)
+        .await
+        .map_err(report_postgres_err)
+}
+
+// every generator whose fake was up against the true completion wins if its fake was picked, and loses otherwise
+async fn update_generator_ratings(
+    con: &mut impl GenericClient,
+    guess: &Guess,
+) -> Result<(), response::AppError> {
+    let presentation_options =
+        presentation_option_service::get_by_presentation_id(con, guess.presentation_id)
+            .await
+            .map_err(report_postgres_err)?;
+
+    let mut article_sections = vec![];
+    for presentation_option in presentation_options {
+        let article_section = article_section_service::get_by_article_section_id(
+            con,
+            &presentation_option.article_section_id,
+        )
+        .await
+        .map_err(report_postgres_err)?
+        .ok_or(response::AppError::ArticleSectionNonexistent)?;
+        article_sections.push((presentation_option, article_section));
+    }
+
+    // without the true completion there's nothing to compare against
+    if !article_sections.iter().any(|(_, x)| x.variant == 0) {
+        return Ok(());
+    }
+
+    for (presentation_option, article_section) in article_sections {
+        let generator_id = match article_section.generator_id {
+            Some(generator_id) if article_section.variant != 0 => generator_id,
+            _ => continue,
+        };
+
+        let (rating, win_count, loss_count) =
+            generator_rating_service::get_recent_by_generator_id(con, generator_id)
+                .await
+                .map_err(report_postgres_err)?
+                .map_or((stats::ELO_INITIAL_RATING, 0, 0), |x| {
+                    (x.rating, x.win_count, x.loss_count)
+                });
+
+        let won = presentation_option.presentation_option_id == guess.presentation_option_id;
+
+        generator_rating_service::add(
+            con,
+            generator_id,
+            guess.guess_id,
+            // the true completion acts as a fixed opponent
+            stats::elo_update(
+                rating,
+                stats::ELO_INITIAL_RATING,
+                if won { 1.0 } else { 0.0 },
+            ),
+            win_count + won as i64,
+            loss_count + !won as i64,
+        )
+        .await
+        .map_err(report_postgres_err)?;
+    }
+
+    Ok(())
+}
+
+// the player wins against the position if they were correct, and the position wins otherwise
+async fn update_skill_ratings(
+    con: &mut impl GenericClient,
+    user: &Identity,
+    guess: &Guess,
+) -> Result<(), response::AppError> {
+    let (user_rating, user_guess_count) =
+        user_rating_service::get_recent_by_creator_user_ids(con, &user.user_ids())
+            .await
+            .map_err(report_postgres_err)?
+            .map_or((stats::ELO_INITIAL_RATING, 0), |x| {
+                (x.rating, x.guess_count)
+            });
+
+    let (position_rating, position_guess_count) =
+        position_difficulty_service::get_recent_by_position(con, guess.article_id, guess.position)
+            .await
+            .map_err(report_postgres_err)?
+            .map_or((stats::ELO_INITIAL_RATING, 0), |x| {
+                (x.rating, x.guess_count)
+            });
+
+    let user_score = if guess.correct { 1.0 } else { 0.0 };
+
+    user_rating_service::add(
+        con,
+        guess.creator_user_id,
+        guess.guess_id,
+        stats::elo_update(user_rating, position_rating, user_score),
+        user_guess_count + 1,
+    )
+    .await
+    .map_err(report_postgres_err)?;
+
+    position_difficulty_service::add(
+        con,
+        guess.article_id,
+        guess.position,
+        guess.guess_id,
+        stats::elo_update(position_rating, user_rating, 1.0 - user_score),
+        position_guess_count + 1,
+    )
+    .await
+    .map_err(report_postgres_err)?;
+
+    Ok(())
+}
+
+// records the pick, updates ratings and moves the session on
+// a ranking lists every option of the presentation, most likely real first, and its first option is the pick
+async fn add_guess(
+    con: &mut impl GenericClient,
+    user: &Identity,
+    presentation_option_id: i64,
+    ranking: Option<Vec<i64>>,
+    confidence: Option<f64>,
+) -> Result<(Guess, Option<Ranking>), response::AppError> {
+    let presentation_option =
+        presentation_option_service::get_by_presentation_option_id(con, presentation_option_id)
+            .await
+            .map_err(report_postgres_err)?
+            .ok_or(response::AppError::PresentationOptionNonexistent)?;
+
+    let presentation =
+        presentation_service::get_by_presentation_id(con, presentation_option.presentation_id)
+            .await
+            .map_err(report_postgres_err)?
+            .ok_or(response::AppError::PresentationNonexistent)?;
+    // validate presentation was served to the correct user
+    if !user.owns(presentation.creator_user_id) {
+        return Err(response::AppError::PresentationNonexistent);
+    }
+
+    // validate that the presentation hasn't been guessed on yet
+    if guess_service::get_by_presentation_id(con, presentation.presentation_id)
+        .await
+        .map_err(report_postgres_err)?
+        .is_some()
+    {
+        return Err(response::AppError::PresentationAlreadyGuessed);
+    }
+
+    let (play_session, play_session_data) =
+        get_open_play_session(con, user, presentation.play_session_id).await?;
+    // validate that the session hasn't moved past this presentation's position
+    if play_session_data.position != presentation.position {
+        return Err(response::AppError::PresentationOutdated);
+    }
+
+    // ensure that the article is still visible
+    article_data_service::get_recent_by_article_id(con, presentation.article_id)
+        .await
+        .map_err(report_postgres_err)?
+        .filter(|x| x.active)
+        .ok_or(response::AppError::ArticleNonexistent)?;
+
+    let article_section = article_section_service::get_by_article_section_id(
+        con,
+        &presentation_option.article_section_id,
+    )
+    .await
+    .map_err(report_postgres_err)?
+    .ok_or(response::AppError::ArticleSectionNonexistent)?;
+
+    // variant 0 is always the true completion
+    let correct = article_section.variant == 0;
+
+    let presentation_options =
+        presentation_option_service::get_by_presentation_id(con, presentation.presentation_id)
+            .await
+            .map_err(report_postgres_err)?;
+    let option_count = presentation_options.len() as i64;
+
+    // a correct pick is worth more the more fakes it was presented alongside
+    // a ranking's first option is scored the same way so both modes share the leaderboard
+    let points = if correct { option_count - 1 } else { 0 };
+
+    let (ranking_points, true_rank) = match &ranking {
+        None => (0, None),
+        // a ranking loses a point for every fake ranked above the true completion
+        Some(ranking) => {
+            // validate that every option was ranked exactly once
+            let mut ranked_ids = ranking.clone();
+            ranked_ids.sort_unstable();
+            let mut option_ids: Vec<i64> = presentation_options
+                .iter()
+                .map(|x| x.presentation_option_id)
+                .collect();
+            option_ids.sort_unstable();
+            if ranked_ids != option_ids {
+                return Err(response::AppError::InvalidRanking);
+            }
+
+            let mut true_rank = None;
+            for presentation_option in presentation_options {
+                let article_section = article_section_service::get_by_article_section_id(
+                    con,
+                    &presentation_option.article_section_id,
+                )
+                .await
+                .map_err(report_postgres_err)?
+                .ok_or(response::AppError::ArticleSectionNonexistent)?;
+
+                if article_section.variant == 0 {
+                    true_rank = ranking
+                        .iter()
+                        .position(|x| *x == presentation_option.presentation_option_id)
+                        .map(|x| x as i64);
+                }
+            }
+
+            (true_rank.map_or(0, |x| option_count - 1 - x), true_rank)
+        }
+    };
+
+    // a player who has seen this position before may remember the answer, so only their first guess is rated
+    let rated = play_session.scored
+        && !guess_service::has_guessed_position(
+            con,
+            &user.user_ids(),
+            presentation.article_id,
+            presentation.position,
+        )
+        .await
+        .map_err(report_postgres_err)?;
+
+    // a confidence below chance would mean the player believes some other option is more likely
+    if let Some(confidence) = confidence {
+        if !(1.0 / option_count as f64..=1.0).contains(&confidence) {
+            return Err(response::AppError::InvalidConfidence);
+        }
+    }
+
+    let guess = guess_service::add(
+        con,
+        user.user_id,
+        presentation.article_id,
+        presentation.position,
+        presentation.presentation_id,
+        presentation_option.presentation_option_id,
+        article_section.article_section_id,
+        correct,
+        points,
+        // a refresh hands back the same presentation, so this counts from when it was first served
+        presentation.creation_time,
+        confidence,
+        confidence.map(|x| stats::brier_score(x, correct)),
+    )
+    .await
+    .map_err(report_postgres_err)?;
+
+    let ranking = match ranking {
+        Some(ranking) => Some(
+            ranking_service::add(
+                con,
+                user.user_id,
+                guess.guess_id,
+                presentation.presentation_id,
+                ranking,
+                true_rank,
+                ranking_points,
+            )
+            .await
+            .map_err(report_postgres_err)?,
+        ),
+        None => None,
+    };
+
+    if rated {
+        update_generator_ratings(con, &guess).await?;
+    }
+
+    // a position with nothing to choose between says nothing about skill
+    if rated && option_count > 1 {
+        update_skill_ratings(con, user, &guess).await?;
+    }
+
+    // move the session on to the next position, closing it after the last one
+    let next_position = article_section_service::get_next_position(
+        con,
+        presentation.article_id,
+        Some(presentation.position),
+    )
+    .await
+    .map_err(report_postgres_err)?;
+
+    play_session_data_service::add(
+        con,
+        user.user_id,
+        play_session.play_session_id,
+        next_position.unwrap_or(presentation.position),
+        next_position.is_none(),
+        false,
+    )
+    .await
+    .map_err(report_postgres_err)?;
+
+    Ok((guess, ranking))
+}
+
+// validates the grid and creates the article with every one of its sections
+// doesn't commit, so that a failure partway through leaves nothing behind
+async fn add_article_import(
+    con: &mut impl GenericClient,
+    user: &Identity,
+    title: String,
+    duration_estimate: i64,
+    sections: Vec<Vec<Option<String>>>,
+    generator_ids: Option<Vec<Option<i64>>>,
+    bibliography: Bibliography,
+) -> Result<ArticleData, response::AppError> {
+    // validate duration
+    if duration_estimate <= 0 {
+        return Err(response::AppError::InvalidDuration);
+    }
+
+    // validate that every position has a true completion
+    if sections.is_empty() || sections.iter().any(|x| !matches!(x.first(), Some(Some(_)))) {
+        return Err(response::AppError::InvalidArticleImport);
+    }
+
+    let generator_ids = generator_ids.unwrap_or_default();
+    let variant_count = sections.iter().map(|x| x.len()).max().unwrap_or(0);
+    // validate that generators only go with fake variants that exist
+    if generator_ids.len() > variant_count || generator_ids.first().is_some_and(|x| x.is_some()) {
+        return Err(response::AppError::InvalidArticleImport);
+    }
+
+    for generator_id in generator_ids.iter().flatten() {
+        // ensure that generator exists and belongs to you
+        let generator = generator_service::get_by_generator_id(con, *generator_id)
+            .await
+            .map_err(report_postgres_err)?
+            .ok_or(response::AppError::GeneratorNonexistent)?;
+        // validate generator is owned by correct user
+        if !user.owns(generator.creator_user_id) {
+            return Err(response::AppError::GeneratorNonexistent);
+        }
+    }
+
+    // create article
+    let article = article_service::add(con, user.user_id)
+        .await
+        .map_err(report_postgres_err)?;
+
+    // create article data
+    let article_data = article_data_service::add(
+        con,
+        user.user_id,
+        article.article_id,
+        title,
+        duration_estimate,
+        bibliography,
+        true,
+    )
+    .await
+    .map_err(report_postgres_err)?;
+
+    // create article sections
+    for (position, variants) in sections.into_iter().enumerate() {
+        for (variant, section_text) in variants.into_iter().enumerate() {
+            let section_text = match section_text {
+                Some(section_text) => section_text,
+                None => continue,
+            };
+
+            article_section_service::add(
+                con,
+                user.user_id,
+                article.article_id,
+                position as i64,
+                variant as i64,
+                section_text,
+                generator_ids.get(variant).copied().flatten(),
+                true,
+            )
+            .await
+            .map_err(report_postgres_err)?;
+        }
+    }
+
+    Ok(article_data)
+}
+
+// the cli imports straight into the database, on behalf of a user that has no api key at hand
+pub async fn import_article_as_user(
+    con: &mut impl GenericClient,
+    creator_user_id: i64,
+    title: String,
+    duration_estimate: i64,
+    sections: Vec<Vec<Option<String>>>,
+) -> Result<ArticleData, response::AppError> {
+    let user = Identity {
+        user_id: creator_user_id,
+        claimed_user_ids: vec![],
+    };
+
+    add_article_import(
+        con,
+        &user,
+        title,
+        duration_estimate,
+        sections,
+        None,
+        Bibliography::default(),
+    )
+    .await
+}
+
+pub async fn info(
+    config: Config,
+    _db: Db,
+    auth_service: AuthService,
+) -> Result<response::Info, response::AppError> {
+    let auth_info = auth_service.info().await.map_err(report_auth_err)?;
+    Ok(response::Info {
+        service: crate::SERVICE_NAME.into(),
+        version_major: 0,
+        version_minor: 0,
+        version_rev: 1,
+        app_pub_origin: config.app_pub_origin,
+        auth_authenticator_href: auth_info.app_authenticator_href,
+        auth_pub_api_href: auth_info.app_pub_api_href,
+    })
+}
+
+pub async fn guest_new(
+    config: Config,
+    db: Db,
+    _: AuthService,
+    _props: request::GuestNewProps,
+) -> Result<response::Guest, response::AppError> {
+    let guest_token_secret = get_guest_token_secret(&config)?;
+
+    let con = &mut *db.lock().await;
+
+    // create guest
+    let guest = guest_service::add(con).await.map_err(report_postgres_err)?;
+
+    // return json
+    Ok(response::Guest {
+        guest_id: guest.guest_id,
+        creation_time: guest.creation_time,
+        user_id: guest_user_id(guest.guest_id),
+        guest_token: guest_token::sign(guest_token_secret, guest.guest_id),
+    })
+}
+
+pub async fn guest_claim_new(
+    config: Config,
+    db: Db,
+    auth_service: AuthService,
+    props: request::GuestClaimNewProps,
+) -> Result<response::GuestClaim, response::AppError> {
+    // validate api key, guests can't claim other guests
+    let user = get_user_if_api_key_valid(&auth_service, props.api_key).await?;
+
+    // validate guest token
+    let guest_id = guest_token::verify(get_guest_token_secret(&config)?, &props.guest_token)
+        .ok_or(response::AppError::GuestNonexistent)?;
+
+    let con = &mut *db.lock().await;
+
+    let mut sp = con.transaction().await.map_err(report_postgres_err)?;
+
+    // ensure that guest exists
+    guest_service::get_by_guest_id(&mut sp, guest_id)
+        .await
+        .map_err(report_postgres_err)?
+        .ok_or(response::AppError::GuestNonexistent)?;
+
+    // validate that nobody has claimed the guest yet
+    if guest_claim_service::get_by_guest_id(&mut sp, guest_id)
+        .await
+        .map_err(report_postgres_err)?
+        .is_some()
+    {
+        return Err(response::AppError::GuestAlreadyClaimed);
+    }
+
+    // create guest claim
+    let guest_claim = guest_claim_service::add(&mut sp, user.user_id, guest_id)
+        .await
+        .map_err(report_postgres_err)?;
+
+    sp.commit().await.map_err(report_postgres_err)?;
+
+    // return json
+    fill_guest_claim(con, guest_claim).await
+}
+
+pub async fn article_new(
+    config: Config,
+    db: Db,
+    auth_service: AuthService,
+    props: request::ArticleNewProps,
+) -> Result<response::ArticleData, response::AppError> {
+    // validate api key
+    let user = get_identity_if_api_key_valid(&config, &db, &auth_service, props.api_key).await?;
+
+    // validate duration estimate if exists
+    if props.duration_estimate <= 0 {
+        return Err(response::AppError::InvalidDuration);
+    }
+
+    let con = &mut *db.lock().await;
+
+    let mut sp = con.transaction().await.map_err(report_postgres_err)?;
+
+    // create article
+    let article = article_service::add(&mut sp, user.user_id)
+        .await
+        .map_err(report_postgres_err)?;
+
+    // create article data
+    let article_data = article_data_service::add(
+        &mut sp,
+        user.user_id,
+        article.article_id,
+        props.title,
+        props.duration_estimate,
+        props
+            .bibliography
+            .map(bibliography_from_props)
+            .unwrap_or_default(),
+        true,
+    )
+    .await
+    .map_err(report_postgres_err)?;
+
+    sp.commit().await.map_err(report_postgres_err)?;
+
+    // return json
+    fill_article_data(con, article_data).await
+}
+
+pub async fn article_import(
+    config: Config,
+    db: Db,
+    auth_service: AuthService,
+    props: request::ArticleImportProps,
+) -> Result<response::ArticleData, response::AppError> {
+    // validate api key
+    let user = get_identity_if_api_key_valid(&config, &db, &auth_service, props.api_key).await?;
+
+    let con = &mut *db.lock().await;
+
+    let mut sp = con.transaction().await.map_err(report_postgres_err)?;
+
+    let article_data = add_article_import(
+        &mut sp,
+        &user,
+        props.title,
+        props.duration_estimate,
+        props.sections,
+        props.generator_ids,
+        props
+            .bibliography
+            .map(bibliography_from_props)
+            .unwrap_or_default(),
+    )
+    .await?;
+
+    sp.commit().await.map_err(report_postgres_err)?;
+
+    // return json
+    fill_article_data(con, article_data).await
+}
+
+pub async fn article_data_new(
+    config: Config,
+    db: Db,
+    auth_service: AuthService,
+    props: request::ArticleDataNewProps,
+) -> Result<response::ArticleData, response::AppError> {
+    // validate api key
+    let user = get_identity_if_api_key_valid(&config, &db, &auth_service, props.api_key).await?;
+
+    // validate duration
+    if props.duration_estimate <= 0 {
+        return Err(response::AppError::InvalidDuration);
+    }
+
+    let con = &mut *db.lock().await;
+
+    let mut sp = con.transaction().await.map_err(report_postgres_err)?;
+
+    // ensure that article exists and belongs to you
+    let article = article_service::get_by_article_id(&mut sp, props.article_id)
+        .await
+        .map_err(report_postgres_err)?
+        .ok_or(response::AppError::ArticleNonexistent)?;
+    // validate article is owned by correct user
+    if !user.owns(article.creator_user_id) {
+        return Err(response::AppError::ArticleNonexistent);
+    }
+
+    let bibliography = match props.bibliography {
+        Some(bibliography) => bibliography_from_props(bibliography),
+        None => article_data_service::get_recent_by_article_id(&mut sp, article.article_id)
+            .await
+            .map_err(report_postgres_err)?
+            .map(|x| x.bibliography)
+            .unwrap_or_default(),
+    };
+
+    // create article data
+    let article_data = article_data_service::add(
+        &mut sp,
+        user.user_id,
+        article.article_id,
+        props.title,
+        props.duration_estimate,
+        bibliography,
+        props.active,
+    )
+    .await
+    .map_err(report_postgres_err)?;
+
+    sp.commit().await.map_err(report_postgres_err)?;
+
+    // return json
+    fill_article_data(con, article_data).await
+}
+
+pub async fn article_section_new(
+    config: Config,
+    db: Db,
+    auth_service: AuthService,
+    props: request::ArticleSectionNewProps,
+) -> Result<response::ArticleSection, response::AppError> {
+    // validate api key
+    let user = get_identity_if_api_key_valid(&config, &db, &auth_service, props.api_key).await?;
+
+    // validate that position is positive
+    if props.position < 0 {
+        return Err(response::AppError::InvalidPosition);
+    }
+
+    let con = &mut *db.lock().await;
+
+    let mut sp = con.transaction().await.map_err(report_postgres_err)?;
+
+    // ensure that article exists and belongs to you
+    let article = article_service::get_by_article_id(&mut sp, props.article_id)
+        .await
+        .map_err(report_postgres_err)?
+        .ok_or(response::AppError::ArticleNonexistent)?;
+    // validate article is owned by correct user
+    if !user.owns(article.creator_user_id) {
+        return Err(response::AppError::ArticleNonexistent);
+    }
+
+    if let Some(generator_id) = props.generator_id {
+        // ensure that generator exists and belongs to you
+        let generator = generator_service::get_by_generator_id(&mut sp, generator_id)
+            .await
+            .map_err(report_postgres_err)?
+            .ok_or(response::AppError::GeneratorNonexistent)?;
+        // validate generator is owned by correct user
+        if !user.owns(generator.creator_user_id) {
+            return Err(response::AppError::GeneratorNonexistent);
+        }
+    }
+
+    // create article section
+    let article_section = article_section_service::add(
+        &mut sp,
+        user.user_id,
+        props.article_id,
+        props.position,
+        props.variant,
+        props.section_text,
+        props.generator_id,
+        props.active,
+    )
+    .await
+    .map_err(report_postgres_err)?;
+
+    sp.commit().await.map_err(report_postgres_err)?;
+
+    // return json
+    fill_article_section(con, article_section).await
+}
+
+pub async fn article_tag_new(
+    config: Config,
+    db: Db,
+    auth_service: AuthService,
+    props: request::ArticleTagNewProps,
+) -> Result<response::ArticleTag, response::AppError> {
+    // validate api key
+    let user = get_identity_if_api_key_valid(&config, &db, &auth_service, props.api_key).await?;
+
+    let name = article_tag_service::normalize_name(&props.name);
+    if name.is_empty() {
+        return Err(response::AppError::InvalidTag);
+    }
+
+    let con = &mut *db.lock().await;
+
+    let mut sp = con.transaction().await.map_err(report_postgres_err)?;
+
+    // ensure that article exists and belongs to you
+    let article = article_service::get_by_article_id(&mut sp, props.article_id)
+        .await
+        .map_err(report_postgres_err)?
+        .ok_or(response::AppError::ArticleNonexistent)?;
+    // validate article is owned by correct user
+    if !user.owns(article.creator_user_id) {
+        return Err(response::AppError::ArticleNonexistent);
+    }
+
+    // create article tag
+    let article_tag = article_tag_service::add(
+        &mut sp,
+        user.user_id,
+        props.article_id,
+        props.kind.as_ref().to_string(),
+        name,
+        props.active,
+    )
+    .await
+    .map_err(report_postgres_err)?;
+
+    sp.commit().await.map_err(report_postgres_err)?;
+
+    // return json
+    fill_article_tag(con, article_tag).await
+}
+
+// returns the revisions that were written, or none if the article already matched
+pub async fn article_revert(
+    config: Config,
+    db: Db,
+    auth_service: AuthService,
+    props: request::ArticleRevertProps,
+) -> Result<Option<response::ArticleEdit>, response::AppError> {
+    // validate api key
+    let user = get_identity_if_api_key_valid(&config, &db, &auth_service, props.api_key).await?;
+
+    let con = &mut *db.lock().await;
+
+    let mut sp = con.transaction().await.map_err(report_postgres_err)?;
+
+    // ensure that article exists and belongs to you
+    let article = article_service::get_by_article_id(&mut sp, props.article_id)
+        .await
+        .map_err(report_postgres_err)?
+        .ok_or(response::AppError::ArticleNonexistent)?;
+    // validate article is owned by correct user
+    if !user.owns(article.creator_user_id) {
+        return Err(response::AppError::ArticleNonexistent);
+    }
+
+    // the state to restore, none where it should be left alone
+    // sections that are current but aren't in target_sections are only deactivated when deactivate_missing is set
+    let (target_data, target_sections, deactivate_missing) =
+        match (props.time, props.article_data_id, props.article_section_id) {
+            (Some(time), None, None) => {
+                let article_data =
+                    article_data_service::get_as_of_by_article_id(&mut sp, props.article_id, time)
+                        .await
+                        .map_err(report_postgres_err)?
+                        .ok_or(response::AppError::InvalidRevision)?;
+                let article_sections = article_section_service::get_as_of_by_article_id(
+                    &mut sp,
+                    props.article_id,
+                    time,
+                )
+                .await
+                .map_err(report_postgres_err)?;
+                (Some(article_data), Some(article_sections), true)
+            }
+            (None, Some(article_data_id), None) => {
+                let article_data =
+                    article_data_service::get_by_article_data_id(&mut sp, article_data_id)
+                        .await
+                        .map_err(report_postgres_err)?
+                        .filter(|x| x.article_id == props.article_id)
+                        .ok_or(response::AppError::ArticleDataNonexistent)?;
+                (Some(article_data), None, false)
+            }
+            (None, None, Some(article_section_id)) => {
+                let article_section = article_section_service::get_by_article_section_id(
+                    &mut sp,
+                    &article_section_id,
+                )
+                .await
+                .map_err(report_postgres_err)?
+                .filter(|x| x.article_id == props.article_id)
+                .ok_or(response::AppError::ArticleSectionNonexistent)?;
+                (None, Some(vec![article_section]), false)
+            }
+            _ => return Err(response::AppError::InvalidRevision),
+        };
+
+    let mut revisions = vec![];
+
+    if let Some(target_data) = target_data {
+        let current_data =
+            article_data_service::get_recent_by_article_id(&mut sp, props.article_id)
+                .await
+                .map_err(report_postgres_err)?;
+
+        let unchanged = current_data.is_some_and(|x| {
+            (x.title, x.duration_estimate, x.bibliography, x.active)
+                == (
+                    target_data.title.clone(),
+                    target_data.duration_estimate,
+                    target_data.bibliography.clone(),
+                    target_data.active,
+                )
+        });
+
+        if !unchanged {
+            let article_data = article_data_service::add(
+                &mut sp,
+                user.user_id,
+                props.article_id,
+                target_data.title,
+                target_data.duration_estimate,
+                target_data.bibliography,
+                target_data.active,
+            )
+            .await
+            .map_err(report_postgres_err)?;
+            revisions.push(ArticleRevision::Data(article_data));
+        }
+    }
+
+    if let Some(target_sections) = target_sections {
+        let mut current_sections: HashMap<(i64, i64), ArticleSection> =
+            article_section_service::get_recent_by_article_id(&mut sp, props.article_id)
+                .await
+                .map_err(report_postgres_err)?
+                .into_iter()
+                .map(|x| ((x.position, x.variant), x))
+                .collect();
+
+        let mut restored_sections = vec![];
+        for target_section in target_sections {
+            let unchanged = current_sections
+                .remove(&(target_section.position, target_section.variant))
+                .is_some_and(|x| {
+                    (x.section_text, x.generator_id, x.active)
+                        == (
+                            target_section.section_text.clone(),
+                            target_section.generator_id,
+                            target_section.active,
+                        )
+                });
+            if !unchanged {
+                restored_sections.push(target_section);
+            }
+        }
+
+        // sections that didn't exist yet at the time are hidden again
+        if deactivate_missing {
+            for (_, current_section) in current_sections {
+                if current_section.active {
+                    restored_sections.push(ArticleSection {
+                        active: false,
+                        ..current_section
+                    });
+                }
+            }
+        }
+
+        restored_sections.sort_by_key(|x| (x.position, x.variant));
+
+        for restored_section in restored_sections {
+            let article_section = article_section_service::add(
+                &mut sp,
+                user.user_id,
+                props.article_id,
+                restored_section.position,
+                restored_section.variant,
+                restored_section.section_text,
+                restored_section.generator_id,
+                restored_section.active,
+            )
+            .await
+            .map_err(report_postgres_err)?;
+            revisions.push(ArticleRevision::Section(article_section));
+        }
+    }
+
+    sp.commit().await.map_err(report_postgres_err)?;
+
+    // return json
+    fill_article_edit(con, revisions).await
+}
+
+pub async fn generator_new(
+    config: Config,
+    db: Db,
+    auth_service: AuthService,
+    props: request::GeneratorNewProps,
+) -> Result<response::Generator, response::AppError> {
+    // validate api key
+    let user = get_identity_if_api_key_valid(&config, &db, &auth_service, props.api_key).await?;
+
+    let con = &mut *db.lock().await;
+
+    // create generator
+    let generator = generator_service::add(
+        con,
+        user.user_id,
+        props.name,
+        props.version,
+        props.parameters,
+    )
+    .await
+    .map_err(report_postgres_err)?;
+
+    // return json
+    fill_generator(con, generator).await
+}
+
+pub async fn play_session_new(
+    config: Config,
+    db: Db,
+    auth_service: AuthService,
+    props: request::PlaySessionNewProps,
+) -> Result<response::PlaySessionData, response::AppError> {
+    // validate api key
+    let user = get_identity_if_api_key_valid(&config, &db, &auth_service, props.api_key).await?;
+
+    let con = &mut *db.lock().await;
+
+    let mut sp = con.transaction().await.map_err(report_postgres_err)?;
+
+    // ensure that the article is still visible
+    article_data_service::get_recent_by_article_id(&mut sp, props.article_id)
+        .await
+        .map_err(report_postgres_err)?
+        .filter(|x| x.active)
+        .ok_or(response::AppError::ArticleNonexistent)?;
+
+    ensure_not_unfinished_daily_challenge(&mut sp, &user, props.article_id).await?;
+
+    // resume the session the player already has going for this article
+    let open_play_session_data = play_session_data_service::get_recent_open_by_article_id(
+        &mut sp,
+        &user.user_ids(),
+        props.article_id,
+    )
+    .await
+    .map_err(report_postgres_err)?;
+
+    let play_session_data = match open_play_session_data {
+        Some(play_session_data) => play_session_data,
+        None => {
+            // sessions start on the first position of the article
+            let position =
+                article_section_service::get_next_position(&mut sp, props.article_id, None)
+                    .await
+                    .map_err(report_postgres_err)?
+                    .ok_or(response::AppError::ArticleSectionNonexistent)?;
+
+            // a replay can't count, since the player may remember the answers
+            let scored = !play_session_service::has_played_article(
+                &mut sp,
+                &user.user_ids(),
+                props.article_id,
+            )
+            .await
+            .map_err(report_postgres_err)?;
+
+            // create play session
+            let play_session = play_session_service::add(
+                &mut sp,
+                user.user_id,
+                props.article_id,
+                None,
+                props.forced_choice,
+                scored,
+            )
+            .await
+            .map_err(report_postgres_err)?;
+
+            // create play session data
+            play_session_data_service::add(
+                &mut sp,
+                user.user_id,
+                play_session.play_session_id,
+                position,
+                false,
+                false,
+            )
+            .await
+            .map_err(report_postgres_err)?
+        }
+    };
+
+    sp.commit().await.map_err(report_postgres_err)?;
+
+    // return json
+    fill_play_session_data(con, play_session_data).await
+}
+
+pub async fn play_session_abandon(
+    config: Config,
+    db: Db,
+    auth_service: AuthService,
+    props: request::PlaySessionAbandonProps,
+) -> Result<response::PlaySessionData, response::AppError> {
+    // validate api key
+    let user = get_identity_if_api_key_valid(&config, &db, &auth_service, props.api_key).await?;
+
+    let con = &mut *db.lock().await;
+
+    let mut sp = con.transaction().await.map_err(report_postgres_err)?;
+
+    let (play_session, play_session_data) =
+        get_open_play_session(&mut sp, &user, props.play_session_id).await?;
+
+    // create play session data
+    let play_session_data = play_session_data_service::add(
+        &mut sp,
+        user.user_id,
+        play_session.play_session_id,
+        play_session_data.position,
+        false,
+        true,
+    )
+    .await
+    .map_err(report_postgres_err)?;
+
+    sp.commit().await.map_err(report_postgres_err)?;
+
+    // return json
+    fill_play_session_data(con, play_session_data).await
+}
+
+pub async fn presentation_new(
+    config: Config,
+    db: Db,
+    auth_service: AuthService,
+    props: request::PresentationNewProps,
+) -> Result<response::Presentation, response::AppError> {
+    // validate api key
+    let user = get_identity_if_api_key_valid(&config, &db, &auth_service, props.api_key).await?;
+
+    let con = &mut *db.lock().await;
+
+    let mut sp = con.transaction().await.map_err(report_postgres_err)?;
+
+    let (play_session, play_session_data) =
+        get_open_play_session(&mut sp, &user, props.play_session_id).await?;
+
+    // ensure that the article is still visible
+    article_data_service::get_recent_by_article_id(&mut sp, play_session.article_id)
+        .await
+        .map_err(report_postgres_err)?
+        .filter(|x| x.active)
+        .ok_or(response::AppError::ArticleNonexistent)?;
+
+    // hand back the presentation that was already served, so a refresh doesn't reshuffle
+    let unguessed_presentation = presentation_service::get_unguessed_by_play_session_id(
+        &mut sp,
+        play_session.play_session_id,
+        play_session_data.position,
+    )
+    .await
+    .map_err(report_postgres_err)?;
+
+    if let Some(presentation) = unguessed_presentation {
+        sp.commit().await.map_err(report_postgres_err)?;
+        return fill_presentation(con, presentation).await;
+    }
+
+    let mut article_sections = article_section_service::get_recent_active_by_position(
+        &mut sp,
+        play_session.article_id,
+        play_session_data.position,
+    )
+    .await
+    .map_err(report_postgres_err)?;
+
+    // validate that the true completion is among the options
+    if !article_sections.iter().any(|x| x.variant == 0) {
+        return Err(response::AppError::InvalidPosition);
+    }
+
+    if play_session.forced_choice {
+        let (true_sections, mut fake_sections): (Vec<_>, Vec<_>) =
+            article_sections.into_iter().partition(|x| x.variant == 0);
+
+        // a forced choice needs a fake to pair the true completion with
+        if fake_sections.is_empty() {
+            return Err(response::AppError::InvalidPosition);
+        }
+
+        let shown_counts: HashMap<i64, i64> =
+            presentation_option_service::get_forced_choice_counts_by_position(
+                &mut sp,
+                play_session.article_id,
+                play_session_data.position,
+            )
+            .await
+            .map_err(report_postgres_err)?
+            .into_iter()
+            .collect();
+
+        // pair the true completion with the least shown fake, so comparisons stay balanced across variants
+        // shuffling first breaks ties at random
+        fake_sections.shuffle(&mut rand::thread_rng());
+        let fake_section = fake_sections
+            .into_iter()
+            .min_by_key(|x| shown_counts.get(&x.variant).copied().unwrap_or(0));
+
+        article_sections = true_sections.into_iter().chain(fake_section).collect();
+    }
+
+    // options are inserted in shuffled order, so neither their order nor their ids give away the variant
+    article_sections.shuffle(&mut rand::thread_rng());
+
+    // create presentation
+    let presentation = presentation_service::add(
+        &mut sp,
+        user.user_id,
+        play_session.play_session_id,
+        play_session.article_id,
+        play_session_data.position,
+    )
+    .await
+    .map_err(report_postgres_err)?;
+
+    for article_section in article_sections {
+        presentation_option_service::add(
+            &mut sp,
+            presentation.presentation_id,
+            article_section.article_section_id,
+        )
+        .await
+        .map_err(report_postgres_err)?;
+    }
+
+    sp.commit().await.map_err(report_postgres_err)?;
+
+    // return json
+    fill_presentation(con, presentation).await
+}
+
+pub async fn guess_new(
+    config: Config,
+    db: Db,
+    auth_service: AuthService,
+    props: request::GuessNewProps,
+) -> Result<response::Guess, response::AppError> {
+    // validate api key
+    let user = get_identity_if_api_key_valid(&config, &db, &auth_service, props.api_key).await?;
+
+    let con = &mut *db.lock().await;
+
+    let mut sp = con.transaction().await.map_err(report_postgres_err)?;
+
+    let (guess, _) = add_guess(
+        &mut sp,
+        &user,
+        props.presentation_option_id,
+        None,
+        props.confidence,
+    )
+    .await?;
+
+    sp.commit().await.map_err(report_postgres_err)?;
+
+    // return json
+    fill_guess(con, guess).await
+}
+
+pub async fn ranking_new(
+    config: Config,
+    db: Db,
+    auth_service: AuthService,
+    props: request::RankingNewProps,
+) -> Result<response::Ranking, response::AppError> {
+    // validate api key
+    let user = get_identity_if_api_key_valid(&config, &db, &auth_service, props.api_key).await?;
+
+    let presentation_option_id = *props
+        .presentation_option_ids
+        .first()
+        .ok_or(response::AppError::InvalidRanking)?;
+
+    let con = &mut *db.lock().await;
+
+    let mut sp = con.transaction().await.map_err(report_postgres_err)?;
+
+    let (_, ranking) = add_guess(
+        &mut sp,
+        &user,
+        presentation_option_id,
+        Some(props.presentation_option_ids),
+        None,
+    )
+    .await?;
+
+    sp.commit().await.map_err(report_postgres_err)?;
+
+    // return json
+    fill_ranking(con, ranking.ok_or(response::AppError::InternalServerError)?).await
+}
+
+pub async fn annotation_new(
+    config: Config,
+    db: Db,
+    auth_service: AuthService,
+    props: request::AnnotationNewProps,
+) -> Result<response::Annotation, response::AppError> {
+    // validate api key
+    let user = get_identity_if_api_key_valid(&config, &db, &auth_service, props.api_key).await?;
+
+    let con = &mut *db.lock().await;
+
+    let mut sp = con.transaction().await.map_err(report_postgres_err)?;
+
+    let guess = guess_service::get_by_guess_id(&mut sp, props.guess_id)
+        .await
+        .map_err(report_postgres_err)?
+        .ok_or(response::AppError::GuessNonexistent)?;
+    // validate guess was made by the correct user
+    if !user.owns(guess.creator_user_id) {
+        return Err(response::AppError::GuessNonexistent);
+    }
+
+    // validate that the section was one of the options the guess was made between
+    if !presentation_option_service::get_by_presentation_id(&mut sp, guess.presentation_id)
+        .await
+        .map_err(report_postgres_err)?
+        .iter()
+        .any(|x| x.article_section_id == props.article_section_id)
+    {
+        return Err(response::AppError::ArticleSectionNonexistent);
+    }
+
+    let article_section =
+        article_section_service::get_by_article_section_id(&mut sp, &props.article_section_id)
+            .await
+            .map_err(report_postgres_err)?
+            .ok_or(response::AppError::ArticleSectionNonexistent)?;
+
+    // validate that the span is a nonempty part of a fake
+    if article_section.variant == 0
+        || props.start_offset < 0
+        || props.start_offset >= props.end_offset
+        || props.end_offset > article_section.section_text.chars().count() as i64
+    {
+        return Err(response::AppError::InvalidAnnotation);
+    }
+
+    // create annotation
+    let annotation = annotation_service::add(
+        &mut sp,
+        user.user_id,
+        guess.guess_id,
+        article_section.article_section_id,
+        props.start_offset,
+        props.end_offset,
+        props.reason.map(|x| x.as_ref().to_string()),
+    )
+    .await
+    .map_err(report_postgres_err)?;
+
+    sp.commit().await.map_err(report_postgres_err)?;
+
+    // return json
+    fill_annotation(con, annotation).await
+}
+
+pub async fn play_next(
+    config: Config,
+    db: Db,
+    auth_service: AuthService,
+    props: request::PlayNextProps,
+) -> Result<Option<response::ArticleData>, response::AppError> {
+    // validate api key
+    let user = get_identity_if_api_key_valid(&config, &db, &auth_service, props.api_key).await?;
+
+    let con = &mut *db.lock().await;
+
+    // today's daily challenge is played through daily/play instead
+    let day = utils::current_time_millis() / DAY_MILLIS;
+    let daily_challenge = daily_challenge_service::get_by_day(con, day)
+        .await
+        .map_err(report_postgres_err)?;
+
+    // every article the player has started a session on counts as played, even if abandoned
+    let article_data = article_data_service::get_recent_unplayed(
+        con,
+        &user.user_ids(),
+        daily_challenge.map(|x| x.article_id),
+        props.strategy,
+    )
+    .await
+    .map_err(report_postgres_err)?;
+
+    // return json, null once everything has been played
+    match article_data {
+        Some(article_data) => Ok(Some(fill_article_data(con, article_data).await?)),
+        None => Ok(None),
+    }
+}
+
+pub async fn daily_challenge_view(
+    config: Config,
+    db: Db,
+    auth_service: AuthService,
+    props: request::DailyChallengeViewProps,
+) -> Result<response::DailyChallenge, response::AppError> {
+    // validate api key
+    let user = get_identity_if_api_key_valid(&config, &db, &auth_service, props.api_key).await?;
+
+    let con = &mut *db.lock().await;
+
+    let mut sp = con.transaction().await.map_err(report_postgres_err)?;
+
+    let daily_challenge = get_or_create_daily_challenge(&mut sp).await?;
+
+    let attempt = play_session_service::get_by_daily_challenge_id(
+        &mut sp,
+        &user.user_ids(),
+        daily_challenge.daily_challenge_id,
+    )
+    .await
+    .map_err(report_postgres_err)?;
+
+    sp.commit().await.map_err(report_postgres_err)?;
+
+    // return json
+    fill_daily_challenge(con, daily_challenge, attempt).await
+}
+
+pub async fn daily_challenge_play(
+    config: Config,
+    db: Db,
+    auth_service: AuthService,
+    props: request::DailyChallengePlayProps,
+) -> Result<response::PlaySessionData, response::AppError> {
+    // validate api key
+    let user = get_identity_if_api_key_valid(&config, &db, &auth_service, props.api_key).await?;
+
+    let con = &mut *db.lock().await;
+
+    let mut sp = con.transaction().await.map_err(report_postgres_err)?;
+
+    let daily_challenge = get_or_create_daily_challenge(&mut sp).await?;
+
+    // ensure that the article is still visible
+    article_data_service::get_recent_by_article_id(&mut sp, daily_challenge.article_id)
+        .await
+        .map_err(report_postgres_err)?
+        .filter(|x| x.active)
+        .ok_or(response::AppError::ArticleNonexistent)?;
+
+    let attempt = play_session_service::get_by_daily_challenge_id(
+        &mut sp,
+        &user.user_ids(),
+        daily_challenge.daily_challenge_id,
+    )
+    .await
+    .map_err(report_postgres_err)?;
+
+    let play_session_data = match attempt {
+        // resume the attempt if it's still going, there's only one per player
+        Some(play_session) => {
+            let play_session_data = play_session_data_service::get_recent_by_play_session_id(
+                &mut sp,
+                play_session.play_session_id,
+            )
+            .await
+            .map_err(report_postgres_err)?
+            .ok_or(response::AppError::PlaySessionNonexistent)?;
+
+            if play_session_data.finish_time.is_some() || play_session_data.abandon_time.is_some() {
+                return Err(response::AppError::DailyChallengeAlreadyAttempted);
+            }
+
+            play_session_data
+        }
+        None => {
+            // sessions start on the first position of the article
+            let position = article_section_service::get_next_position(
+                &mut sp,
+                daily_challenge.article_id,
+                None,
+            )
+            .await
+            .map_err(report_postgres_err)?
+            .ok_or(response::AppError::ArticleSectionNonexistent)?;
+
+            // daily challenges can repeat an article, which the player may remember
+            let scored = !play_session_service::has_played_article(
+                &mut sp,
+                &user.user_ids(),
+                daily_challenge.article_id,
+            )
+            .await
+            .map_err(report_postgres_err)?;
+
+            // create play session
+            let play_session = play_session_service::add(
+                &mut sp,
+                user.user_id,
+                daily_challenge.article_id,
+                Some(daily_challenge.daily_challenge_id),
+                false,
+                scored,
+            )
+            .await
+            .map_err(report_postgres_err)?;
+
+            // create play session data
+            play_session_data_service::add(
+                &mut sp,
+                user.user_id,
+                play_session.play_session_id,
+                position,
+                false,
+                false,
+            )
+            .await
+            .map_err(report_postgres_err)?
+        }
+    };
+
+    sp.commit().await.map_err(report_postgres_err)?;
+
+    // return json
+    fill_play_session_data(con, play_session_data).await
+}
+
+pub async fn article_view(
+    config: Config,
+    db: Db,
+    auth_service: AuthService,
+    props: request::ArticleViewProps,
+) -> Result<Vec<response::Article>, response::AppError> {
+    // validate api key
+    let user =
+        get_identity_if_api_key_valid(&config, &db, &auth_service, props.api_key.clone()).await?;
+
+    let con = &mut *db.lock().await;
+    // get users
+    let articles = article_service::query(con, props)
+        .await
+        .map_err(report_postgres_err)?;
+
+    // return articles
+    let mut resp_articles = vec![];
+    for u in articles
+        .into_iter()
+        .filter(|u| user.owns(u.creator_user_id))
+    {
+        resp_articles.push(fill_article(con, u).await?);
+    }
+
+    Ok(resp_articles)
+}
+
+pub async fn article_data_view(
+    config: Config,
+    db: Db,
+    auth_service: AuthService,
+    props: request::ArticleDataViewProps,
+) -> Result<Vec<response::ArticleData>, response::AppError> {
+    // validate api key
+    let user =
+        get_identity_if_api_key_valid(&config, &db, &auth_service, props.api_key.clone()).await?;
+
+    let con = &mut *db.lock().await;
+    // get users
+    let article_data = article_data_service::query(con, props)
+        .await
+        .map_err(report_postgres_err)?;
+
+    // return article_datas
+    let mut resp_article_datas = vec![];
+    for u in article_data
+        .into_iter()
+        .filter(|u| user.owns(u.creator_user_id))
+    {
+        resp_article_datas.push(fill_article_data(con, u).await?);
+    }
+
+    Ok(resp_article_datas)
+}
+
+pub async fn article_section_view(
+    config: Config,
+    db: Db,
+    auth_service: AuthService,
+    props: request::ArticleSectionViewProps,
+) -> Result<Vec<response::ArticleSection>, response::AppError> {
+    // validate api key
+    let user =
+        get_identity_if_api_key_valid(&config, &db, &auth_service, props.api_key.clone()).await?;
+
+    let con = &mut *db.lock().await;
+    // get users
+    let article_section = article_section_service::query(con, props)
+        .await
+        .map_err(report_postgres_err)?;
+
+    // return article_sections
+    let mut resp_article_sections = vec![];
+    for u in article_section
+        .into_iter()
+        .filter(|u| user.owns(u.creator_user_id))
+    {
+        resp_article_sections.push(fill_article_section(con, u).await?);
+    }
+
+    Ok(resp_article_sections)
+}
+
+pub async fn article_tag_view(
+    config: Config,
+    db: Db,
+    auth_service: AuthService,
+    props: request::ArticleTagViewProps,
+) -> Result<Vec<response::ArticleTag>, response::AppError> {
+    // validate api key
+    let user =
+        get_identity_if_api_key_valid(&config, &db, &auth_service, props.api_key.clone()).await?;
+
+    let con = &mut *db.lock().await;
+    // get tags
+    let article_tag = article_tag_service::query(con, props)
+        .await
+        .map_err(report_postgres_err)?;
+
+    // return article_tags
+    let mut resp_article_tags = vec![];
+    for u in article_tag
+        .into_iter()
+        .filter(|u| user.owns(u.creator_user_id))
+    {
+        resp_article_tags.push(fill_article_tag(con, u).await?);
+    }
+
+    Ok(resp_article_tags)
+}
+
+pub async fn generator_view(
+    config: Config,
+    db: Db,
+    auth_service: AuthService,
+    props: request::GeneratorViewProps,
+) -> Result<Vec<response::Generator>, response::AppError> {
+    // validate api key
+    let user =
+        get_identity_if_api_key_valid(&config, &db, &auth_service, props.api_key.clone()).await?;
+
+    let con = &mut *db.lock().await;
+    // get generators
+    let generator = generator_service::query(con, props)
+        .await
+        .map_err(report_postgres_err)?;
+
+    // return generators
+    let mut resp_generators = vec![];
+    for u in generator
+        .into_iter()
+        .filter(|u| user.owns(u.creator_user_id))
+    {
+        resp_generators.push(fill_generator(con, u).await?);
+    }
+
+    Ok(resp_generators)
+}
+
+pub async fn generator_leaderboard(
+    config: Config,
+    db: Db,
+    auth_service: AuthService,
+    props: request::GeneratorLeaderboardProps,
+) -> Result<Vec<response::GeneratorRating>, response::AppError> {
+    // validate api key
+    let user = get_identity_if_api_key_valid(&config, &db, &auth_service, props.api_key).await?;
+
+    let con = &mut *db.lock().await;
+    // get ratings of the generators you own
+    let generator_rating =
+        generator_rating_service::get_recent_by_creator_user_ids(con, &user.user_ids())
+            .await
+            .map_err(report_postgres_err)?;
+
+    // return generator ratings
+    let mut resp_generator_ratings = vec![];
+    for u in generator_rating {
+        resp_generator_ratings.push(fill_generator_rating(con, u).await?);
+    }
+
+    Ok(resp_generator_ratings)
+}
+
+pub async fn article_section_stats(
+    config: Config,
+    db: Db,
+    auth_service: AuthService,
+    props: request::ArticleSectionStatsProps,
+) -> Result<Vec<response::ArticleSectionStats>, response::AppError> {
+    // validate api key
+    let user = get_identity_if_api_key_valid(&config, &db, &auth_service, props.api_key).await?;
+
+    let con = &mut *db.lock().await;
+
+    // ensure that article exists and belongs to you
+    let article = article_service::get_by_article_id(con, props.article_id)
+        .await
+        .map_err(report_postgres_err)?
+        .ok_or(response::AppError::ArticleNonexistent)?;
+    // validate article is owned by correct user
+    if !user.owns(article.creator_user_id) {
+        return Err(response::AppError::ArticleNonexistent);
+    }
+
+    let article_section_stats =
+        article_section_stats_service::query(con, props.article_id, props.position)
+            .await
+            .map_err(report_postgres_err)?;
+
+    // return stats
+    let mut resp_article_section_stats = vec![];
+    for u in article_section_stats {
+        resp_article_section_stats.push(fill_article_section_stats(con, u).await?);
+    }
+
+    Ok(resp_article_section_stats)
+}
+
+pub async fn article_latency_stats(
+    config: Config,
+    db: Db,
+    auth_service: AuthService,
+    props: request::ArticleLatencyStatsProps,
+) -> Result<response::ArticleLatencyStats, response::AppError> {
+    // validate api key
+    let user = get_identity_if_api_key_valid(&config, &db, &auth_service, props.api_key).await?;
+
+    let con = &mut *db.lock().await;
+
+    // ensure that article exists and belongs to you
+    let article = article_service::get_by_article_id(con, props.article_id)
+        .await
+        .map_err(report_postgres_err)?
+        .ok_or(response::AppError::ArticleNonexistent)?;
+    // validate article is owned by correct user
+    if !user.owns(article.creator_user_id) {
+        return Err(response::AppError::ArticleNonexistent);
+    }
+
+    let latency_stats = latency_stats_service::get_by_article_id(con, props.article_id)
+        .await
+        .map_err(report_postgres_err)?;
+
+    // the row without a position is the summary over the whole article
+    let mut latency = None;
+    let mut positions = vec![];
+    for u in latency_stats {
+        match u.position {
+            Some(position) => positions.push(response::PositionLatencyStats {
+                position,
+                latency: fill_latency_stats(&u),
+            }),
+            None => latency = Some(fill_latency_stats(&u)),
+        }
+    }
+
+    Ok(response::ArticleLatencyStats {
+        article: fill_article(con, article).await?,
+        latency: latency.ok_or(response::AppError::InternalServerError)?,
+        positions,
+    })
+}
+
+pub async fn article_bibliography_view(
+    config: Config,
+    db: Db,
+    auth_service: AuthService,
+    props: request::ArticleBibliographyViewProps,
+) -> Result<response::ArticleBibliography, response::AppError> {
+    // validate api key
+    let user = get_identity_if_api_key_valid(&config, &db, &auth_service, props.api_key).await?;
+
+    let con = &mut *db.lock().await;
+
+    let article = article_service::get_by_article_id(con, props.article_id)
+        .await
+        .map_err(report_postgres_err)?
+        .ok_or(response::AppError::ArticleNonexistent)?;
+
+    // players only get to see it once they've finished the article
+    if !user.owns(article.creator_user_id) {
+        let finished = play_session_data_service::has_finished_article(
+            con,
+            &user.user_ids(),
+            props.article_id,
+        )
+        .await
+        .map_err(report_postgres_err)?;
+        if !finished {
+            return Err(response::AppError::ArticleNotFinished);
+        }
+    }
+
+    let article_data = article_data_service::get_recent_by_article_id(con, props.article_id)
+        .await
+        .map_err(report_postgres_err)?
+        .ok_or(response::AppError::ArticleNonexistent)?;
+
+    let bibliography = fill_bibliography(article_data.bibliography.clone());
+
+    Ok(response::ArticleBibliography {
+        article_data: fill_article_data(con, article_data).await?,
+        bibliography,
+    })
+}
+
+pub async fn article_history_view(
+    config: Config,
+    db: Db,
+    auth_service: AuthService,
+    props: request::ArticleHistoryViewProps,
+) -> Result<response::ArticleHistory, response::AppError> {
+    // validate api key
+    let user = get_identity_if_api_key_valid(&config, &db, &auth_service, props.api_key).await?;
+
+    let con = &mut *db.lock().await;
+
+    // ensure that article exists and belongs to you
+    let article = article_service::get_by_article_id(con, props.article_id)
+        .await
+        .map_err(report_postgres_err)?
+        .ok_or(response::AppError::ArticleNonexistent)?;
+    // validate article is owned by correct user
+    if !user.owns(article.creator_user_id) {
+        return Err(response::AppError::ArticleNonexistent);
+    }
+
+    let article_data = article_data_service::get_by_article_id(con, props.article_id)
+        .await
+        .map_err(report_postgres_err)?;
 
-pub async fn get_user_if_api_key_valid(
-    auth_service: &auth_service_api::client::AuthService,
-    api_key: String,
-) -> Result<User, response::AppError> {
-    auth_service
-        .get_user_by_api_key_if_valid(api_key)
+    let article_sections = article_section_service::get_by_article_id(con, props.article_id)
         .await
-        .map_err(report_auth_err)
-}
+        .map_err(report_postgres_err)?;
 
-pub async fn info(
-    config: Config,
-    _db: Db,
-    auth_service: AuthService,
-) -> Result<response::Info, response::AppError> {
-    let auth_info = auth_service.info().await.map_err(report_auth_err)?;
-    Ok(response::Info {
-        service: crate::SERVICE_NAME.into(),
-        version_major: 0,
-        version_minor: 0,
-        version_rev: 1,
-        app_pub_origin: config.app_pub_origin,
-        auth_authenticator_href: auth_info.app_authenticator_href,
-        auth_pub_api_href: auth_info.app_pub_api_href,
+    let mut revisions: Vec<ArticleRevision> = article_data
+        .into_iter()
+        .map(ArticleRevision::Data)
+        .chain(article_sections.into_iter().map(ArticleRevision::Section))
+        .collect();
+    // stable, so revisions made at the same time keep data before sections
+    revisions.sort_by_key(|x| x.creation_time());
+
+    // a new edit starts whenever the author changes or there's a long enough pause
+    let mut grouped_revisions: Vec<Vec<ArticleRevision>> = vec![];
+    for revision in revisions {
+        match grouped_revisions.last_mut() {
+            Some(group)
+                if group.last().is_some_and(|x| {
+                    x.creator_user_id() == revision.creator_user_id()
+                        && revision.creation_time() - x.creation_time() < EDIT_GAP_MILLIS
+                }) =>
+            {
+                group.push(revision)
+            }
+            _ => grouped_revisions.push(vec![revision]),
+        }
+    }
+
+    let mut edits = vec![];
+    for group in grouped_revisions {
+        edits.extend(fill_article_edit(con, group).await?);
+    }
+
+    Ok(response::ArticleHistory {
+        article: fill_article(con, article).await?,
+        edits,
     })
 }
 
-pub async fn article_new(
-    _config: Config,
+pub async fn article_data_diff(
+    config: Config,
     db: Db,
     auth_service: AuthService,
-    props: request::ArticleNewProps,
-) -> Result<response::ArticleData, response::AppError> {
+    props: request::ArticleDataDiffProps,
+) -> Result<response::ArticleDataDiff, response::AppError> {
     // validate api key
-    let user = get_user_if_api_key_valid(&auth_service, props.api_key).await?;
-
-    // validate duration estimate if exists
-    if props.duration_estimate <= 0 {
-        return Err(response::AppError::InvalidDuration);
-    }
+    let user = get_identity_if_api_key_valid(&config, &db, &auth_service, props.api_key).await?;
 
     let con = &mut *db.lock().await;
 
-    let mut sp = con.transaction().await.map_err(report_postgres_err)?;
+    let from = article_data_service::get_by_article_data_id(con, props.from_article_data_id)
+        .await
+        .map_err(report_postgres_err)?
+        .ok_or(response::AppError::ArticleDataNonexistent)?;
 
-    // create article
-    let article = article_service::add(&mut sp, user.user_id)
+    let to = article_data_service::get_by_article_data_id(con, props.to_article_data_id)
         .await
-        .map_err(report_postgres_err)?;
+        .map_err(report_postgres_err)?
+        .ok_or(response::AppError::ArticleDataNonexistent)?;
 
-    // create article data
-    let article_data = article_data_service::add(
-        &mut sp,
-        user.user_id,
-        article.article_id,
-        props.title,
-        props.duration_estimate,
-        true,
-    )
-    .await
-    .map_err(report_postgres_err)?;
+    if from.article_id != to.article_id {
+        return Err(response::AppError::InvalidRevision);
+    }
 
-    sp.commit().await.map_err(report_postgres_err)?;
+    // ensure that article exists and belongs to you
+    let article = article_service::get_by_article_id(con, from.article_id)
+        .await
+        .map_err(report_postgres_err)?
+        .ok_or(response::AppError::ArticleNonexistent)?;
+    // validate article is owned by correct user
+    if !user.owns(article.creator_user_id) {
+        return Err(response::AppError::ArticleNonexistent);
+    }
 
-    // return json
-    fill_article_data(con, article_data).await
+    let title = fill_diff(&from.title, &to.title)?;
+
+    Ok(response::ArticleDataDiff {
+        from: fill_article_data(con, from).await?,
+        to: fill_article_data(con, to).await?,
+        title,
+    })
 }
 
-pub async fn article_data_new(
-    _config: Config,
+pub async fn article_section_diff(
+    config: Config,
     db: Db,
     auth_service: AuthService,
-    props: request::ArticleDataNewProps,
-) -> Result<response::ArticleData, response::AppError> {
+    props: request::ArticleSectionDiffProps,
+) -> Result<response::ArticleSectionDiff, response::AppError> {
     // validate api key
-    let user = get_user_if_api_key_valid(&auth_service, props.api_key).await?;
-
-    // validate duration
-    if props.duration_estimate <= 0 {
-        return Err(response::AppError::InvalidDuration);
-    }
+    let user = get_identity_if_api_key_valid(&config, &db, &auth_service, props.api_key).await?;
 
     let con = &mut *db.lock().await;
 
-    let mut sp = con.transaction().await.map_err(report_postgres_err)?;
+    let from =
+        article_section_service::get_by_article_section_id(con, &props.from_article_section_id)
+            .await
+            .map_err(report_postgres_err)?
+            .ok_or(response::AppError::ArticleSectionNonexistent)?;
+
+    let to = article_section_service::get_by_article_section_id(con, &props.to_article_section_id)
+        .await
+        .map_err(report_postgres_err)?
+        .ok_or(response::AppError::ArticleSectionNonexistent)?;
+
+    if (from.article_id, from.position, from.variant) != (to.article_id, to.position, to.variant) {
+        return Err(response::AppError::InvalidRevision);
+    }
 
     // ensure that article exists and belongs to you
-    let article = article_service::get_by_article_id(&mut sp, props.article_id)
+    let article = article_service::get_by_article_id(con, from.article_id)
         .await
         .map_err(report_postgres_err)?
         .ok_or(response::AppError::ArticleNonexistent)?;
     // validate article is owned by correct user
-    if article.creator_user_id != user.user_id {
+    if !user.owns(article.creator_user_id) {
         return Err(response::AppError::ArticleNonexistent);
     }
 
-    // create article data
-    let article_data = article_data_service::add(
-        &mut sp,
-        user.user_id,
-        article.article_id,
-        props.title,
-        props.duration_estimate,
-        props.active,
-    )
-    .await
-    .map_err(report_postgres_err)?;
-
-    sp.commit().await.map_err(report_postgres_err)?;
+    let section_text = fill_diff(&from.section_text, &to.section_text)?;
 
-    // return json
-    fill_article_data(con, article_data).await
+    Ok(response::ArticleSectionDiff {
+        from: fill_article_section(con, from).await?,
+        to: fill_article_section(con, to).await?,
+        section_text,
+    })
 }
 
-pub async fn article_section_new(
-    _config: Config,
+pub async fn annotation_heatmap(
+    config: Config,
     db: Db,
     auth_service: AuthService,
-    props: request::ArticleSectionNewProps,
-) -> Result<response::ArticleSection, response::AppError> {
+    props: request::AnnotationHeatmapProps,
+) -> Result<response::AnnotationHeatmap, response::AppError> {
     // validate api key
-    let user = get_user_if_api_key_valid(&auth_service, props.api_key).await?;
-
-    // validate that position is positive
-    if props.position < 0 {
-        return Err(response::AppError::InvalidPosition);
-    }
+    let user = get_identity_if_api_key_valid(&config, &db, &auth_service, props.api_key).await?;
 
     let con = &mut *db.lock().await;
 
-    let mut sp = con.transaction().await.map_err(report_postgres_err)?;
+    let article_section =
+        article_section_service::get_by_article_section_id(con, &props.article_section_id)
+            .await
+            .map_err(report_postgres_err)?
+            .ok_or(response::AppError::ArticleSectionNonexistent)?;
 
     // ensure that article exists and belongs to you
-    let article = article_service::get_by_article_id(&mut sp, props.article_id)
+    let article = article_service::get_by_article_id(con, article_section.article_id)
         .await
         .map_err(report_postgres_err)?
         .ok_or(response::AppError::ArticleNonexistent)?;
     // validate article is owned by correct user
-    if article.creator_user_id != user.user_id {
-        return Err(response::AppError::ArticleNonexistent);
+    if !user.owns(article.creator_user_id) {
+        return Err(response::AppError::ArticleSectionNonexistent);
     }
 
-    // create article section
-    let article_section = article_section_service::add(
-        &mut sp,
-        user.user_id,
-        props.article_id,
-        props.position,
-        props.variant,
-        props.section_text,
-        props.active,
-    )
-    .await
-    .map_err(report_postgres_err)?;
+    let annotations = annotation_service::get_by_article_section_id(con, props.article_section_id)
+        .await
+        .map_err(report_postgres_err)?;
 
-    sp.commit().await.map_err(report_postgres_err)?;
+    let mut reason_counts: BTreeMap<Option<String>, i64> = BTreeMap::new();
+    for annotation in &annotations {
+        *reason_counts.entry(annotation.reason.clone()).or_insert(0) += 1;
+    }
 
-    // return json
-    fill_article_section(con, article_section).await
+    Ok(response::AnnotationHeatmap {
+        article_section: fill_article_section(con, article_section).await?,
+        annotation_count: annotations.len() as i64,
+        spans: annotation_heatmap_spans(&annotations),
+        reasons: reason_counts
+            .into_iter()
+            .map(|(reason, count)| response::AnnotationReasonCount { reason, count })
+            .collect(),
+    })
 }
 
-pub async fn article_view(
-    _config: Config,
+pub async fn play_session_data_view(
+    config: Config,
     db: Db,
     auth_service: AuthService,
-    props: request::ArticleViewProps,
-) -> Result<Vec<response::Article>, response::AppError> {
+    props: request::PlaySessionDataViewProps,
+) -> Result<Vec<response::PlaySessionData>, response::AppError> {
     // validate api key
-    let user = get_user_if_api_key_valid(&auth_service, props.api_key.clone()).await?;
+    let user =
+        get_identity_if_api_key_valid(&config, &db, &auth_service, props.api_key.clone()).await?;
 
     let con = &mut *db.lock().await;
-    // get users
-    let articles = article_service::query(con, props)
+    // get play session data
+    let play_session_data = play_session_data_service::query(con, props)
         .await
         .map_err(report_postgres_err)?;
 
-    // return articles
-    let mut resp_articles = vec![];
-    for u in articles
+    // return play_session_datas
+    let mut resp_play_session_datas = vec![];
+    for u in play_session_data
         .into_iter()
-        .filter(|u| u.creator_user_id == user.user_id)
+        .filter(|u| user.owns(u.creator_user_id))
     {
-        resp_articles.push(fill_article(con, u).await?);
+        resp_play_session_datas.push(fill_play_session_data(con, u).await?);
     }
 
-    Ok(resp_articles)
+    Ok(resp_play_session_datas)
 }
 
-pub async fn article_data_view(
-    _config: Config,
+pub async fn guess_view(
+    config: Config,
     db: Db,
     auth_service: AuthService,
-    props: request::ArticleDataViewProps,
-) -> Result<Vec<response::ArticleData>, response::AppError> {
+    props: request::GuessViewProps,
+) -> Result<Vec<response::Guess>, response::AppError> {
     // validate api key
-    let user = get_user_if_api_key_valid(&auth_service, props.api_key.clone()).await?;
+    let user =
+        get_identity_if_api_key_valid(&config, &db, &auth_service, props.api_key.clone()).await?;
 
     let con = &mut *db.lock().await;
-    // get users
-    let article_data = article_data_service::query(con, props)
+    // get guesses
+    let guess = guess_service::query(con, props)
         .await
         .map_err(report_postgres_err)?;
 
-    // return article_datas
-    let mut resp_article_datas = vec![];
-    for u in article_data
-        .into_iter()
-        .filter(|u| u.creator_user_id == user.user_id)
-    {
-        resp_article_datas.push(fill_article_data(con, u).await?);
+    // return guesses
+    let mut resp_guesses = vec![];
+    for u in guess.into_iter().filter(|u| user.owns(u.creator_user_id)) {
+        resp_guesses.push(fill_guess(con, u).await?);
     }
 
-    Ok(resp_article_datas)
+    Ok(resp_guesses)
 }
 
-pub async fn article_section_view(
-    _config: Config,
+pub async fn user_stats_view(
+    config: Config,
     db: Db,
     auth_service: AuthService,
-    props: request::ArticleSectionViewProps,
-) -> Result<Vec<response::ArticleSection>, response::AppError> {
+    props: request::UserStatsViewProps,
+) -> Result<response::UserStats, response::AppError> {
     // validate api key
-    let user = get_user_if_api_key_valid(&auth_service, props.api_key.clone()).await?;
+    let user = get_identity_if_api_key_valid(&config, &db, &auth_service, props.api_key).await?;
 
     let con = &mut *db.lock().await;
-    // get users
-    let article_section = article_section_service::query(con, props)
+
+    let user_ratings = user_rating_service::get_by_creator_user_ids(con, &user.user_ids())
         .await
         .map_err(report_postgres_err)?;
 
-    // return article_sections
-    let mut resp_article_sections = vec![];
-    for u in article_section
-        .into_iter()
-        .filter(|u| u.creator_user_id == user.user_id)
-    {
-        resp_article_sections.push(fill_article_section(con, u).await?);
+    let (rating, guess_count) = user_ratings
+        .last()
+        .map_or((stats::ELO_INITIAL_RATING, 0), |x| {
+            (x.rating, x.guess_count)
+        });
+
+    let mut rating_history = vec![];
+    for u in user_ratings {
+        rating_history.push(fill_user_rating(con, u).await?);
     }
 
-    Ok(resp_article_sections)
+    let latency_stats = latency_stats_service::get_by_creator_user_ids(con, &user.user_ids())
+        .await
+        .map_err(report_postgres_err)?;
+
+    let calibration_bins =
+        calibration_service::get_by_creator_user_ids(con, &user.user_ids(), CALIBRATION_BIN_COUNT)
+            .await
+            .map_err(report_postgres_err)?;
+
+    let confident_guess_count: i64 = calibration_bins.iter().map(|x| x.guess_count).sum();
+    let mean_brier_score = if confident_guess_count > 0 {
+        Some(
+            calibration_bins
+                .iter()
+                .map(|x| x.mean_brier_score * x.guess_count as f64)
+                .sum::<f64>()
+                / confident_guess_count as f64,
+        )
+    } else {
+        None
+    };
+
+    Ok(response::UserStats {
+        creator_user_id: user.user_id,
+        rating,
+        guess_count,
+        rating_history,
+        latency: fill_latency_stats(&latency_stats),
+        mean_brier_score,
+        calibration: calibration_bins
+            .into_iter()
+            .map(fill_calibration_bin)
+            .collect(),
+    })
+}
+
+pub async fn leaderboard_view(
+    config: Config,
+    db: Db,
+    auth_service: AuthService,
+    props: request::LeaderboardViewProps,
+) -> Result<Vec<response::LeaderboardEntry>, response::AppError> {
+    // validate api key
+    let user =
+        get_identity_if_api_key_valid(&config, &db, &auth_service, props.api_key.clone()).await?;
+
+    let (min_creation_time, article_id) = match props.kind {
+        request::LeaderboardKind::AllTime => (None, None),
+        request::LeaderboardKind::Weekly => {
+            (Some(utils::current_time_millis() - WEEK_MILLIS), None)
+        }
+        request::LeaderboardKind::Article => (
+            None,
+            Some(
+                props
+                    .article_id
+                    .ok_or(response::AppError::ArticleNonexistent)?,
+            ),
+        ),
+    };
+
+    let entries = {
+        let con = &mut *db.lock().await;
+        leaderboard_service::query(con, min_creation_time, article_id, LEADERBOARD_SIZE)
+            .await
+            .map_err(report_postgres_err)?
+    };
+
+    // we can't join on user data, so display names have to come from the auth service
+    // unclaimed guests have no user data, and the auth service won't answer to a guest token
+    let user_ids: Vec<i64> = entries
+        .iter()
+        .map(|x| x.creator_user_id)
+        .filter(|x| !is_guest_user_id(*x))
+        .collect();
+    let usernames: HashMap<i64, String> = if is_guest_user_id(user.user_id) || user_ids.is_empty() {
+        HashMap::new()
+    } else {
+        auth_service
+            .user_data_view(auth_service_api::request::UserDataViewProps {
+                user_data_id: None,
+                min_creation_time: None,
+                max_creation_time: None,
+                creator_user_id: Some(user_ids),
+                username: None,
+                only_recent: true,
+                api_key: props.api_key,
+            })
+            .await
+            .map_err(report_auth_err)?
+            .into_iter()
+            .map(|x| (x.creator_user_id, x.username))
+            .collect()
+    };
+
+    // return leaderboard entries
+    Ok(entries
+        .into_iter()
+        .enumerate()
+        .map(|(i, x)| response::LeaderboardEntry {
+            rank: i as i64 + 1,
+            creator_user_id: x.creator_user_id,
+            username: usernames.get(&x.creator_user_id).cloned(),
+            guest: is_guest_user_id(x.creator_user_id),
+            points: x.points,
+            correct_count: x.correct_count,
+            guess_count: x.guess_count,
+        })
+        .collect())
 }
 
 pub async fn article_data_public_view(
@@ -362,6 +3038,14 @@ pub async fn article_data_public_view(
         max_duration_estimate: props.max_duration_estimate,
         active: Some(true),
         only_recent: true,
+        as_of: None,
+        author: None,
+        venue: None,
+        min_year: None,
+        max_year: None,
+        doi: None,
+        license: None,
+        tag: props.tag,
         api_key: String::from(""),
     };
 
@@ -396,8 +3080,11 @@ pub async fn article_section_public_view(
         article_id: props.article_id,
         position: props.position,
         variant: props.variant,
+        generator_id: None,
         active: Some(true),
         only_recent: true,
+        as_of: None,
+        tag: props.tag,
         api_key: String::from(""),
     };
 
@@ -406,11 +3093,104 @@ pub async fn article_section_public_view(
         .await
         .map_err(report_postgres_err)?;
 
+    // sections of articles that are still being played would give away the true completion
+    // those can only be seen through a presentation
+    let mut article_active = HashMap::new();
+
     // return article_sections
     let mut resp_article_sections = vec![];
     for u in article_section.into_iter() {
-        resp_article_sections.push(fill_article_section(con, u).await?);
+        let active = match article_active.get(&u.article_id) {
+            Some(active) => *active,
+            None => {
+                let active = article_data_service::get_recent_by_article_id(con, u.article_id)
+                    .await
+                    .map_err(report_postgres_err)?
+                    .is_some_and(|x| x.active);
+                article_active.insert(u.article_id, active);
+                active
+            }
+        };
+
+        if !active {
+            resp_article_sections.push(fill_article_section(con, u).await?);
+        }
     }
 
     Ok(resp_article_sections)
 }
+
+pub async fn article_tag_public_view(
+    _config: Config,
+    db: Db,
+    _: AuthService,
+    props: request::ArticleTagViewPublicProps,
+) -> Result<Vec<response::ArticleTag>, response::AppError> {
+    // rearrange props
+    let props = request::ArticleTagViewProps {
+        article_tag_id: None,
+        min_creation_time: None,
+        max_creation_time: None,
+        creator_user_id: None,
+        article_id: props.article_id,
+        kind: props.kind,
+        name: props.name,
+        active: Some(true),
+        only_recent: true,
+        api_key: String::from(""),
+    };
+
+    let con = &mut *db.lock().await;
+    // get tags
+    let article_tag = article_tag_service::query(con, props)
+        .await
+        .map_err(report_postgres_err)?;
+
+    // only tags of articles that are still up, like article_data_public_view
+    let mut article_active = HashMap::new();
+
+    // return article_tags
+    let mut resp_article_tags = vec![];
+    for u in article_tag.into_iter() {
+        let active = match article_active.get(&u.article_id) {
+            Some(active) => *active,
+            None => {
+                let active = article_data_service::get_recent_by_article_id(con, u.article_id)
+                    .await
+                    .map_err(report_postgres_err)?
+                    .is_some_and(|x| x.active);
+                article_active.insert(u.article_id, active);
+                active
+            }
+        };
+
+        if active {
+            resp_article_tags.push(fill_article_tag(con, u).await?);
+        }
+    }
+
+    Ok(resp_article_tags)
+}
+
+pub async fn article_tag_count_public_view(
+    _config: Config,
+    db: Db,
+    _: AuthService,
+    props: request::ArticleTagCountPublicProps,
+) -> Result<Vec<response::ArticleTagCount>, response::AppError> {
+    let con = &mut *db.lock().await;
+
+    let article_tag_counts =
+        article_tag_service::get_counts(con, props.kind.map(|x| x.as_ref().to_string()))
+            .await
+            .map_err(report_postgres_err)?;
+
+    Ok(article_tag_counts
+        .into_iter()
+        .map(|x| response::ArticleTagCount {
+            kind: x.kind,
+            name: x.name,
+            article_count: x.article_count,
+        })
+        .collect())
+}
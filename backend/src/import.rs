@@ -0,0 +1,151 @@
+use calamine::Reader;
+use clap::Args;
+use std::error::Error;
+use std::path::{Path, PathBuf};
+use tokio_postgres::{Client, NoTls};
+
+use super::handlers;
+use super::utils;
+
+#[derive(Args, Clone)]
+pub struct ImportOpts {
+    #[clap(long)]
+    database_url: String,
+    /// csv or xlsx file with a header row, then one row per position and one column per variant
+    #[clap(long)]
+    file: PathBuf,
+    #[clap(long)]
+    title: String,
+    /// in milliseconds
+    #[clap(long, default_value_t = 10 * 60 * 1000)]
+    duration_estimate: i64,
+    /// the user the article is created for
+    #[clap(long)]
+    creator_user_id: i64,
+    /// validate the file against the database without writing anything
+    #[clap(long)]
+    dry_run: bool,
+}
+
+// unlike the service, commands give up straight away if the database can't be reached
+pub async fn connect(database_url: &str) -> Result<Client, tokio_postgres::Error> {
+    let (client, connection) = tokio_postgres::connect(database_url, NoTls).await?;
+
+    // The connection object performs the actual communication with the database,
+    // so spawn it off to run on its own.
+    tokio::spawn(async move {
+        if let Err(e) = connection.await {
+            utils::log(utils::Event {
+                msg: e.to_string(),
+                source: e.source().map(|x| x.to_string()),
+                severity: utils::SeverityKind::Error,
+            });
+        }
+    });
+
+    Ok(client)
+}
+
+// same layout as loadcsv.py expects: the first row is a header, and empty cells are skipped
+fn read_sections(file: &Path) -> Result<Vec<Vec<Option<String>>>, Box<dyn Error>> {
+    let extension = file
+        .extension()
+        .and_then(|x| x.to_str())
+        .map(|x| x.to_lowercase());
+
+    let sections = match extension.as_deref() {
+        Some("csv") => {
+            let mut reader = csv::ReaderBuilder::new().flexible(true).from_path(file)?;
+            let mut sections = vec![];
+            for record in reader.records() {
+                sections.push(
+                    record?
+                        .iter()
+                        .map(|x| Some(x.to_string()).filter(|x| !x.is_empty()))
+                        .collect(),
+                );
+            }
+            sections
+        }
+        Some("xlsx") | Some("xls") | Some("ods") => {
+            let mut workbook = calamine::open_workbook_auto(file)?;
+            let range = workbook
+                .worksheet_range_at(0)
+                .ok_or("spreadsheet has no sheets")??;
+            range
+                .rows()
+                .skip(1)
+                .map(|row| {
+                    row.iter()
+                        .map(|x| match x {
+                            calamine::Data::Empty => None,
+                            x => Some(x.to_string()),
+                        })
+                        .collect()
+                })
+                .collect()
+        }
+        _ => return Err("file must be a csv, xlsx, xls or ods".into()),
+    };
+
+    Ok(sections)
+}
+
+// the first position without a true completion in its first column, if any
+fn position_missing_true_completion(sections: &[Vec<Option<String>>]) -> Option<usize> {
+    sections
+        .iter()
+        .position(|x| !matches!(x.first(), Some(Some(_))))
+}
+
+pub async fn import(opts: ImportOpts) -> Result<(), Box<dyn Error>> {
+    let sections = read_sections(&opts.file)?;
+
+    let variant_count = sections.iter().map(|x| x.len()).max().unwrap_or(0);
+    let section_count = sections.iter().flatten().flatten().count();
+    println!(
+        "read {} positions, {} variants and {} sections from {}",
+        sections.len(),
+        variant_count,
+        section_count,
+        opts.file.display()
+    );
+
+    // report exactly where the file is wrong, rather than just that it is
+    if sections.is_empty() {
+        return Err("the file has no rows below the header".into());
+    }
+    if let Some(position) = position_missing_true_completion(&sections) {
+        return Err(format!(
+            "row {} (position {}): missing the true completion in the first column",
+            // rows count from 1 and the header comes first
+            position + 2,
+            position
+        )
+        .into());
+    }
+
+    let mut client = connect(&opts.database_url).await?;
+
+    // everything goes through one transaction, so a dry run is just an import that gets rolled back
+    let mut sp = client.transaction().await?;
+
+    let article_data = handlers::import_article_as_user(
+        &mut sp,
+        opts.creator_user_id,
+        opts.title,
+        opts.duration_estimate,
+        sections,
+    )
+    .await?;
+
+    if opts.dry_run {
+        sp.rollback().await?;
+        println!("dry run: the file is valid, nothing was written");
+    } else {
+        sp.commit().await?;
+        println!("imported article {}", article_data.article_id);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,72 @@
+use super::db_types::*;
+use tokio_postgres::GenericClient;
+
+impl From<tokio_postgres::row::Row> for LatencyStats {
+  fn from(row: tokio_postgres::Row) -> LatencyStats {
+    LatencyStats {
+      position: row.get("position"),
+      guess_count: row.get("guess_count"),
+      mean_latency: row.get("mean_latency"),
+      median_latency: row.get("median_latency"),
+      p90_latency: row.get("p90_latency"),
+    }
+  }
+}
+
+// positions with a single option aren't a choice, so their guesses are left out
+// so are replays, where the player may answer from memory
+static LATENCY_AGGREGATES: &str = "
+  count(*) guess_count,
+  avg(g.latency)::float8 mean_latency,
+  percentile_cont(0.5) WITHIN GROUP (ORDER BY g.latency) median_latency,
+  percentile_cont(0.9) WITHIN GROUP (ORDER BY g.latency) p90_latency
+  FROM guess g
+  INNER JOIN presentation p ON p.presentation_id = g.presentation_id
+  INNER JOIN play_session ps ON ps.play_session_id = p.play_session_id
+  WHERE ps.scored
+  AND (SELECT count(*) FROM presentation_option po WHERE po.presentation_id = g.presentation_id) > 1";
+
+// one summary per position of the article, followed by the summary over the whole article
+pub async fn get_by_article_id(
+  con: &mut impl GenericClient,
+  article_id: i64,
+) -> Result<Vec<LatencyStats>, tokio_postgres::Error> {
+  let sql = [
+    "SELECT g.position,",
+    LATENCY_AGGREGATES,
+    " AND g.article_id = $1",
+    " GROUP BY ROLLUP(g.position)",
+    " ORDER BY g.position NULLS LAST",
+  ]
+  .join("\n");
+
+  let stmnt = con.prepare(&sql).await?;
+
+  let results = con
+    .query(&stmnt, &[&article_id])
+    .await?
+    .into_iter()
+    .map(|row| row.into())
+    .collect();
+
+  Ok(results)
+}
+
+// the summary over every guess the given users made
+pub async fn get_by_creator_user_ids(
+  con: &mut impl GenericClient,
+  creator_user_ids: &[i64],
+) -> Result<LatencyStats, tokio_postgres::Error> {
+  let sql = [
+    "SELECT NULL::bigint AS position,",
+    LATENCY_AGGREGATES,
+    " AND g.creator_user_id = ANY($1)",
+  ]
+  .join("\n");
+
+  let stmnt = con.prepare(&sql).await?;
+
+  let result = con.query_one(&stmnt, &[&creator_user_ids]).await?.into();
+
+  Ok(result)
+}
@@ -0,0 +1,56 @@
+use super::db_types::*;
+use tokio_postgres::GenericClient;
+
+impl From<tokio_postgres::row::Row> for LeaderboardEntry {
+  fn from(row: tokio_postgres::Row) -> LeaderboardEntry {
+    LeaderboardEntry {
+      creator_user_id: row.get("creator_user_id"),
+      points: row.get("points"),
+      correct_count: row.get("correct_count"),
+      guess_count: row.get("guess_count"),
+    }
+  }
+}
+
+// ranks players by the points they scored on guesses matching the filters
+// guesses made by a guest count towards the user who claimed them
+// only a player's first guess at each position of an article counts, so replaying an article scores nothing
+pub async fn query(
+  con: &mut impl GenericClient,
+  min_creation_time: Option<i64>,
+  article_id: Option<i64>,
+  limit: i64,
+) -> Result<Vec<LeaderboardEntry>, tokio_postgres::Error> {
+  let sql = [
+    "SELECT gu.creator_user_id,",
+    "  sum(gu.points)::bigint points,",
+    "  count(*) FILTER (WHERE gu.correct) correct_count,",
+    "  count(*) guess_count",
+    " FROM (",
+    "   SELECT DISTINCT ON (coalesce(gc.creator_user_id, g.creator_user_id), g.article_id, g.position)",
+    "     coalesce(gc.creator_user_id, g.creator_user_id) creator_user_id,",
+    "     g.creation_time, g.article_id, g.correct, g.points",
+    "   FROM guess g",
+    "   LEFT JOIN guest_claim gc ON gc.guest_id = -g.creator_user_id",
+    "   ORDER BY coalesce(gc.creator_user_id, g.creator_user_id), g.article_id, g.position, g.guess_id",
+    " ) gu",
+    " WHERE 1 = 1",
+    " AND ($1::bigint IS NULL OR gu.creation_time >= $1)",
+    " AND ($2::bigint IS NULL OR gu.article_id = $2)",
+    " GROUP BY gu.creator_user_id",
+    " ORDER BY points DESC, correct_count DESC, gu.creator_user_id",
+    " LIMIT $3",
+  ]
+  .join("\n");
+
+  let stmnt = con.prepare(&sql).await?;
+
+  let results = con
+    .query(&stmnt, &[&min_creation_time, &article_id, &limit])
+    .await?
+    .into_iter()
+    .map(|row| row.into())
+    .collect();
+
+  Ok(results)
+}
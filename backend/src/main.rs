@@ -1,4 +1,4 @@
-use clap::Parser;
+use clap::{Args, Parser, Subcommand};
 use std::error::Error;
 use std::sync::Arc;
 use tokio_postgres::{Client, NoTls};
@@ -6,6 +6,12 @@ use warp::Filter;
 
 use tokio::sync::Mutex;
 
+mod dataset;
+mod diff;
+mod guest_token;
+mod import;
+mod research;
+mod stats;
 mod utils;
 
 use auth_service_api::client::AuthService;
@@ -15,9 +21,29 @@ mod request;
 mod response;
 
 // db web stuff
+mod annotation_service;
 mod article_data_service;
 mod article_section_service;
+mod article_section_stats_service;
 mod article_service;
+mod article_tag_service;
+mod calibration_service;
+mod daily_challenge_service;
+mod generator_rating_service;
+mod generator_service;
+mod guess_record_service;
+mod guess_service;
+mod guest_claim_service;
+mod guest_service;
+mod latency_stats_service;
+mod leaderboard_service;
+mod play_session_data_service;
+mod play_session_service;
+mod position_difficulty_service;
+mod presentation_option_service;
+mod presentation_service;
+mod ranking_service;
+mod user_rating_service;
 
 mod api;
 mod db_types;
@@ -26,7 +52,31 @@ mod handlers;
 static SERVICE_NAME: &str = "critica-service";
 
 #[derive(Parser, Clone)]
+#[clap(args_conflicts_with_subcommands = true)]
 struct Opts {
+    #[clap(subcommand)]
+    command: Option<Command>,
+    // without a subcommand the service runs, so existing deployments keep working
+    #[clap(flatten)]
+    serve: Option<ServeOpts>,
+}
+
+#[derive(Subcommand, Clone)]
+enum Command {
+    /// run the web service
+    Serve(ServeOpts),
+    /// import an article from a spreadsheet straight into the database
+    Import(import::ImportOpts),
+    /// export articles to a jsonl dataset
+    Export(dataset::ExportOpts),
+    /// import a jsonl dataset written by export
+    ImportDataset(dataset::ImportDatasetOpts),
+    /// export the guesses made on articles, with players pseudonymized, for offline analysis
+    ExportGuesses(research::ExportGuessesOpts),
+}
+
+#[derive(Args, Clone)]
+struct ServeOpts {
     #[clap(long)]
     app_pub_origin: String,
     #[clap(long)]
@@ -35,23 +85,65 @@ struct Opts {
     auth_service_url: String,
     #[clap(long)]
     port: u16,
+    /// signs guest tokens, guests can't play unless this is set
+    #[clap(long)]
+    guest_token_secret: Option<String>,
 }
 
 #[derive(Clone)]
 pub struct Config {
     pub app_pub_origin: String,
+    pub guest_token_secret: Option<String>,
 }
 
 pub type Db = Arc<Mutex<Client>>;
 
 #[tokio::main]
 async fn main() {
-    let Opts {
+    let opts = Opts::parse();
+    // clap requires the serve arguments whenever no subcommand is given
+    let command = opts
+        .command
+        .or(opts.serve.map(Command::Serve))
+        .expect("either a subcommand or the serve arguments");
+
+    match command {
+        Command::Serve(opts) => serve(opts).await,
+        Command::Import(opts) => {
+            if let Err(e) = import::import(opts).await {
+                eprintln!("import failed: {}", e);
+                std::process::exit(1);
+            }
+        }
+        Command::Export(opts) => {
+            if let Err(e) = dataset::export(opts).await {
+                eprintln!("export failed: {}", e);
+                std::process::exit(1);
+            }
+        }
+        Command::ImportDataset(opts) => {
+            if let Err(e) = dataset::import(opts).await {
+                eprintln!("import failed: {}", e);
+                std::process::exit(1);
+            }
+        }
+        Command::ExportGuesses(opts) => {
+            if let Err(e) = research::export_guesses(opts).await {
+                eprintln!("export failed: {}", e);
+                std::process::exit(1);
+            }
+        }
+    }
+}
+
+async fn serve(opts: ServeOpts) {
+    let ServeOpts {
         database_url,
         app_pub_origin,
         auth_service_url,
         port,
-    } = Opts::parse();
+        guest_token_secret,
+    } = opts;
 
     let (client, connection) = loop {
         match tokio_postgres::connect(&database_url, NoTls).await {
@@ -89,7 +181,14 @@ async fn main() {
         });
     });
 
-    let api = api::api(Config { app_pub_origin }, db, auth_service);
+    let api = api::api(
+        Config {
+            app_pub_origin,
+            guest_token_secret,
+        },
+        db,
+        auth_service,
+    );
 
     warp::serve(api.with(log)).run(([0, 0, 0, 0], port)).await;
 }
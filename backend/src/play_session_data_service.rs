@@ -0,0 +1,176 @@
+use super::db_types::*;
+use super::request;
+use super::utils::current_time_millis;
+use tokio_postgres::GenericClient;
+
+impl From<tokio_postgres::row::Row> for PlaySessionData {
+  // select * from play_session_data order only, otherwise it will fail
+  fn from(row: tokio_postgres::Row) -> PlaySessionData {
+    PlaySessionData {
+      play_session_data_id: row.get("play_session_data_id"),
+      creation_time: row.get("creation_time"),
+      creator_user_id: row.get("creator_user_id"),
+      play_session_id: row.get("play_session_id"),
+      position: row.get("position"),
+      finish_time: row.get("finish_time"),
+      abandon_time: row.get("abandon_time"),
+    }
+  }
+}
+
+// finished and abandoned are stamped with the creation time of this row
+pub async fn add(
+  con: &mut impl GenericClient,
+  creator_user_id: i64,
+  play_session_id: i64,
+  position: i64,
+  finished: bool,
+  abandoned: bool,
+) -> Result<PlaySessionData, tokio_postgres::Error> {
+  let creation_time = current_time_millis();
+  let finish_time = finished.then_some(creation_time);
+  let abandon_time = abandoned.then_some(creation_time);
+
+  let play_session_data_id = con
+    .query_one(
+      "INSERT INTO
+       play_session_data(
+           creation_time,
+           creator_user_id,
+           play_session_id,
+           position,
+           finish_time,
+           abandon_time
+       )
+       VALUES ($1, $2, $3, $4, $5, $6)
+       RETURNING play_session_data_id
+      ",
+      &[
+        &creation_time,
+        &creator_user_id,
+        &play_session_id,
+        &position,
+        &finish_time,
+        &abandon_time,
+      ],
+    )
+    .await?
+    .get(0);
+
+  Ok(PlaySessionData {
+    play_session_data_id,
+    creation_time,
+    creator_user_id,
+    play_session_id,
+    position,
+    finish_time,
+    abandon_time,
+  })
+}
+
+pub async fn get_recent_by_play_session_id(
+  con: &mut impl GenericClient,
+  play_session_id: i64,
+) -> Result<Option<PlaySessionData>, tokio_postgres::Error> {
+  let result = con
+    .query_opt(
+      "SELECT * FROM recent_play_session_data WHERE play_session_id=$1",
+      &[&play_session_id],
+    )
+    .await?
+    .map(|x| x.into());
+
+  Ok(result)
+}
+
+// the session a player should resume when they come back to an article
+pub async fn get_recent_open_by_article_id(
+  con: &mut impl GenericClient,
+  creator_user_ids: &[i64],
+  article_id: i64,
+) -> Result<Option<PlaySessionData>, tokio_postgres::Error> {
+  let result = con
+    .query_opt(
+      "SELECT psd.* FROM recent_play_session_data psd
+       INNER JOIN play_session ps ON psd.play_session_id = ps.play_session_id
+       WHERE ps.creator_user_id = ANY($1) AND ps.article_id=$2
+       AND psd.finish_time IS NULL AND psd.abandon_time IS NULL
+       ORDER BY psd.play_session_id DESC
+       LIMIT 1",
+      &[&creator_user_ids, &article_id],
+    )
+    .await?
+    .map(|x| x.into());
+
+  Ok(result)
+}
+
+// whether any of the given users have played the article to the end
+pub async fn has_finished_article(
+  con: &mut impl GenericClient,
+  creator_user_ids: &[i64],
+  article_id: i64,
+) -> Result<bool, tokio_postgres::Error> {
+  let result = con
+    .query_one(
+      "SELECT EXISTS (
+         SELECT 1 FROM recent_play_session_data psd
+         INNER JOIN play_session ps ON psd.play_session_id = ps.play_session_id
+         WHERE ps.creator_user_id = ANY($1) AND ps.article_id=$2
+         AND psd.finish_time IS NOT NULL
+       )",
+      &[&creator_user_ids, &article_id],
+    )
+    .await?
+    .get(0);
+
+  Ok(result)
+}
+
+pub async fn query(
+  con: &mut impl GenericClient,
+  props: request::PlaySessionDataViewProps,
+) -> Result<Vec<PlaySessionData>, tokio_postgres::Error> {
+  let sql = [
+    if props.only_recent {
+      "SELECT psd.* FROM recent_play_session_data psd"
+    } else {
+      "SELECT psd.* FROM play_session_data psd"
+    },
+    " INNER JOIN play_session ps ON psd.play_session_id = ps.play_session_id",
+    " WHERE 1 = 1",
+    " AND ($1::bigint[] IS NULL OR psd.play_session_data_id = ANY($1))",
+    " AND ($2::bigint   IS NULL OR psd.creation_time >= $2)",
+    " AND ($3::bigint   IS NULL OR psd.creation_time <= $3)",
+    " AND ($4::bigint[] IS NULL OR psd.creator_user_id = ANY($4))",
+    " AND ($5::bigint[] IS NULL OR psd.play_session_id = ANY($5))",
+    " AND ($6::bigint[] IS NULL OR ps.article_id = ANY($6))",
+    " AND ($7::bool     IS NULL OR (psd.finish_time IS NOT NULL) = $7)",
+    " AND ($8::bool     IS NULL OR (psd.abandon_time IS NOT NULL) = $8)",
+    " ORDER BY psd.play_session_data_id",
+  ]
+  .join("\n");
+
+  let stmnt = con.prepare(&sql).await?;
+
+  let results = con
+    .query(
+      &stmnt,
+      &[
+        &props.play_session_data_id,
+        &props.min_creation_time,
+        &props.max_creation_time,
+        &props.creator_user_id,
+        &props.play_session_id,
+        &props.article_id,
+        &props.finished,
+        &props.abandoned,
+      ],
+    )
+    .await?
+    .into_iter()
+    .map(|row| row.into())
+    .collect();
+
+  Ok(results)
+}
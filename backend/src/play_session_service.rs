@@ -0,0 +1,120 @@
+use super::db_types::*;
+use super::utils::current_time_millis;
+use tokio_postgres::GenericClient;
+
+impl From<tokio_postgres::row::Row> for PlaySession {
+  // select * from play_session order only, otherwise it will fail
+  fn from(row: tokio_postgres::Row) -> PlaySession {
+    PlaySession {
+      play_session_id: row.get("play_session_id"),
+      creation_time: row.get("creation_time"),
+      creator_user_id: row.get("creator_user_id"),
+      article_id: row.get("article_id"),
+      daily_challenge_id: row.get("daily_challenge_id"),
+      forced_choice: row.get("forced_choice"),
+      scored: row.get("scored"),
+    }
+  }
+}
+
+pub async fn add(
+  con: &mut impl GenericClient,
+  creator_user_id: i64,
+  article_id: i64,
+  daily_challenge_id: Option<i64>,
+  forced_choice: bool,
+  scored: bool,
+) -> Result<PlaySession, tokio_postgres::Error> {
+  let creation_time = current_time_millis();
+
+  let play_session_id = con
+    .query_one(
+      "INSERT INTO
+       play_session(
+           creation_time,
+           creator_user_id,
+           article_id,
+           daily_challenge_id,
+           forced_choice,
+           scored
+       )
+       VALUES($1, $2, $3, $4, $5, $6)
+       RETURNING play_session_id
+      ",
+      &[
+        &creation_time,
+        &creator_user_id,
+        &article_id,
+        &daily_challenge_id,
+        &forced_choice,
+        &scored,
+      ],
+    )
+    .await?
+    .get(0);
+
+  Ok(PlaySession {
+    play_session_id,
+    creation_time,
+    creator_user_id,
+    article_id,
+    daily_challenge_id,
+    forced_choice,
+    scored,
+  })
+}
+
+pub async fn get_by_play_session_id(
+  con: &mut impl GenericClient,
+  play_session_id: i64,
+) -> Result<Option<PlaySession>, tokio_postgres::Error> {
+  let result = con
+    .query_opt(
+      "SELECT * FROM play_session WHERE play_session_id=$1",
+      &[&play_session_id],
+    )
+    .await?
+    .map(|x| x.into());
+
+  Ok(result)
+}
+
+// the attempt any of the given users made at the daily challenge
+pub async fn get_by_daily_challenge_id(
+  con: &mut impl GenericClient,
+  creator_user_ids: &[i64],
+  daily_challenge_id: i64,
+) -> Result<Option<PlaySession>, tokio_postgres::Error> {
+  let result = con
+    .query_opt(
+      "SELECT * FROM play_session
+       WHERE creator_user_id = ANY($1) AND daily_challenge_id=$2
+       ORDER BY play_session_id
+       LIMIT 1",
+      &[&creator_user_ids, &daily_challenge_id],
+    )
+    .await?
+    .map(|x| x.into());
+
+  Ok(result)
+}
+
+// whether any of the given users have ever had a session on the article, finished or not
+pub async fn has_played_article(
+  con: &mut impl GenericClient,
+  creator_user_ids: &[i64],
+  article_id: i64,
+) -> Result<bool, tokio_postgres::Error> {
+  let result = con
+    .query_one(
+      "SELECT EXISTS (
+         SELECT 1 FROM play_session
+         WHERE creator_user_id = ANY($1) AND article_id=$2
+       )",
+      &[&creator_user_ids, &article_id],
+    )
+    .await?
+    .get(0);
+
+  Ok(result)
+}
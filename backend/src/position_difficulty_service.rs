@@ -0,0 +1,81 @@
+use super::db_types::*;
+use super::utils::current_time_millis;
+use tokio_postgres::GenericClient;
+
+impl From<tokio_postgres::row::Row> for PositionDifficulty {
+  // select * from position_difficulty order only, otherwise it will fail
+  fn from(row: tokio_postgres::Row) -> PositionDifficulty {
+    PositionDifficulty {
+      position_difficulty_id: row.get("position_difficulty_id"),
+      creation_time: row.get("creation_time"),
+      article_id: row.get("article_id"),
+      position: row.get("position"),
+      guess_id: row.get("guess_id"),
+      rating: row.get("rating"),
+      guess_count: row.get("guess_count"),
+    }
+  }
+}
+
+pub async fn add(
+  con: &mut impl GenericClient,
+  article_id: i64,
+  position: i64,
+  guess_id: i64,
+  rating: f64,
+  guess_count: i64,
+) -> Result<PositionDifficulty, tokio_postgres::Error> {
+  let creation_time = current_time_millis();
+
+  let position_difficulty_id = con
+    .query_one(
+      "INSERT INTO
+       position_difficulty(
+           creation_time,
+           article_id,
+           position,
+           guess_id,
+           rating,
+           guess_count
+       )
+       VALUES ($1, $2, $3, $4, $5, $6)
+       RETURNING position_difficulty_id
+      ",
+      &[
+        &creation_time,
+        &article_id,
+        &position,
+        &guess_id,
+        &rating,
+        &guess_count,
+      ],
+    )
+    .await?
+    .get(0);
+
+  Ok(PositionDifficulty {
+    position_difficulty_id,
+    creation_time,
+    article_id,
+    position,
+    guess_id,
+    rating,
+    guess_count,
+  })
+}
+
+pub async fn get_recent_by_position(
+  con: &mut impl GenericClient,
+  article_id: i64,
+  position: i64,
+) -> Result<Option<PositionDifficulty>, tokio_postgres::Error> {
+  let result = con
+    .query_opt(
+      "SELECT * FROM recent_position_difficulty WHERE article_id=$1 AND position=$2",
+      &[&article_id, &position],
+    )
+    .await?
+    .map(|x| x.into());
+
+  Ok(result)
+}
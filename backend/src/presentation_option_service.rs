@@ -0,0 +1,99 @@
+use super::db_types::*;
+use tokio_postgres::GenericClient;
+
+impl From<tokio_postgres::row::Row> for PresentationOption {
+  // select * from presentation_option order only, otherwise it will fail
+  fn from(row: tokio_postgres::Row) -> PresentationOption {
+    PresentationOption {
+      presentation_option_id: row.get("presentation_option_id"),
+      presentation_id: row.get("presentation_id"),
+      article_section_id: row.get("article_section_id"),
+    }
+  }
+}
+
+pub async fn add(
+  con: &mut impl GenericClient,
+  presentation_id: i64,
+  article_section_id: i64,
+) -> Result<PresentationOption, tokio_postgres::Error> {
+  let presentation_option_id = con
+    .query_one(
+      "INSERT INTO
+       presentation_option(
+           presentation_id,
+           article_section_id
+       )
+       VALUES ($1, $2)
+       RETURNING presentation_option_id
+      ",
+      &[&presentation_id, &article_section_id],
+    )
+    .await?
+    .get(0);
+
+  Ok(PresentationOption {
+    presentation_option_id,
+    presentation_id,
+    article_section_id,
+  })
+}
+
+pub async fn get_by_presentation_option_id(
+  con: &mut impl GenericClient,
+  presentation_option_id: i64,
+) -> Result<Option<PresentationOption>, tokio_postgres::Error> {
+  let result = con
+    .query_opt(
+      "SELECT * FROM presentation_option WHERE presentation_option_id=$1",
+      &[&presentation_option_id],
+    )
+    .await?
+    .map(|x| x.into());
+
+  Ok(result)
+}
+
+// returns options in the (shuffled) order they were served in
+pub async fn get_by_presentation_id(
+  con: &mut impl GenericClient,
+  presentation_id: i64,
+) -> Result<Vec<PresentationOption>, tokio_postgres::Error> {
+  let results = con
+    .query(
+      "SELECT * FROM presentation_option WHERE presentation_id=$1
+       ORDER BY presentation_option_id",
+      &[&presentation_id],
+    )
+    .await?
+    .into_iter()
+    .map(|x| x.into())
+    .collect();
+
+  Ok(results)
+}
+
+// how often each fake variant at the position has been shown in forced choice sessions
+// returns pairs of (variant, shown count), variants that were never shown are left out
+pub async fn get_forced_choice_counts_by_position(
+  con: &mut impl GenericClient,
+  article_id: i64,
+  position: i64,
+) -> Result<Vec<(i64, i64)>, tokio_postgres::Error> {
+  let results = con
+    .query(
+      "SELECT ase.variant, count(*) FROM presentation_option po
+       INNER JOIN presentation p ON po.presentation_id = p.presentation_id
+       INNER JOIN play_session ps ON p.play_session_id = ps.play_session_id
+       INNER JOIN article_section ase ON po.article_section_id = ase.article_section_id
+       WHERE ps.forced_choice AND p.article_id=$1 AND p.position=$2 AND ase.variant != 0
+       GROUP BY ase.variant",
+      &[&article_id, &position],
+    )
+    .await?
+    .into_iter()
+    .map(|x| (x.get(0), x.get(1)))
+    .collect();
+
+  Ok(results)
+}
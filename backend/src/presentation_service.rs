@@ -0,0 +1,96 @@
+use super::db_types::*;
+use super::utils::current_time_millis;
+use tokio_postgres::GenericClient;
+
+impl From<tokio_postgres::row::Row> for Presentation {
+  // select * from presentation order only, otherwise it will fail
+  fn from(row: tokio_postgres::Row) -> Presentation {
+    Presentation {
+      presentation_id: row.get("presentation_id"),
+      creation_time: row.get("creation_time"),
+      creator_user_id: row.get("creator_user_id"),
+      play_session_id: row.get("play_session_id"),
+      article_id: row.get("article_id"),
+      position: row.get("position"),
+    }
+  }
+}
+
+pub async fn add(
+  con: &mut impl GenericClient,
+  creator_user_id: i64,
+  play_session_id: i64,
+  article_id: i64,
+  position: i64,
+) -> Result<Presentation, tokio_postgres::Error> {
+  let creation_time = current_time_millis();
+
+  let presentation_id = con
+    .query_one(
+      "INSERT INTO
+       presentation(
+           creation_time,
+           creator_user_id,
+           play_session_id,
+           article_id,
+           position
+       )
+       VALUES ($1, $2, $3, $4, $5)
+       RETURNING presentation_id
+      ",
+      &[
+        &creation_time,
+        &creator_user_id,
+        &play_session_id,
+        &article_id,
+        &position,
+      ],
+    )
+    .await?
+    .get(0);
+
+  Ok(Presentation {
+    presentation_id,
+    creation_time,
+    creator_user_id,
+    play_session_id,
+    article_id,
+    position,
+  })
+}
+
+pub async fn get_by_presentation_id(
+  con: &mut impl GenericClient,
+  presentation_id: i64,
+) -> Result<Option<Presentation>, tokio_postgres::Error> {
+  let result = con
+    .query_opt(
+      "SELECT * FROM presentation WHERE presentation_id=$1",
+      &[&presentation_id],
+    )
+    .await?
+    .map(|x| x.into());
+
+  Ok(result)
+}
+
+// a presentation that was served but never answered, e.g. because the page was refreshed
+pub async fn get_unguessed_by_play_session_id(
+  con: &mut impl GenericClient,
+  play_session_id: i64,
+  position: i64,
+) -> Result<Option<Presentation>, tokio_postgres::Error> {
+  let result = con
+    .query_opt(
+      "SELECT p.* FROM presentation p
+       WHERE p.play_session_id=$1 AND p.position=$2
+       AND NOT EXISTS (SELECT 1 FROM guess g WHERE g.presentation_id = p.presentation_id)
+       ORDER BY p.presentation_id DESC
+       LIMIT 1",
+      &[&play_session_id, &position],
+    )
+    .await?
+    .map(|x| x.into());
+
+  Ok(result)
+}
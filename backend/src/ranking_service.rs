@@ -0,0 +1,82 @@
+use super::db_types::*;
+use super::utils::current_time_millis;
+use tokio_postgres::GenericClient;
+
+impl From<tokio_postgres::row::Row> for Ranking {
+  // select * from ranking order only, otherwise it will fail
+  fn from(row: tokio_postgres::Row) -> Ranking {
+    Ranking {
+      ranking_id: row.get("ranking_id"),
+      creation_time: row.get("creation_time"),
+      creator_user_id: row.get("creator_user_id"),
+      guess_id: row.get("guess_id"),
+      presentation_id: row.get("presentation_id"),
+      presentation_option_ids: row.get("presentation_option_ids"),
+      true_rank: row.get("true_rank"),
+      points: row.get("points"),
+    }
+  }
+}
+
+pub async fn add(
+  con: &mut impl GenericClient,
+  creator_user_id: i64,
+  guess_id: i64,
+  presentation_id: i64,
+  presentation_option_ids: Vec<i64>,
+  true_rank: Option<i64>,
+  points: i64,
+) -> Result<Ranking, tokio_postgres::Error> {
+  let creation_time = current_time_millis();
+
+  let ranking_id = con
+    .query_one(
+      "INSERT INTO
+       ranking(
+           creation_time,
+           creator_user_id,
+           guess_id,
+           presentation_id,
+           presentation_option_ids,
+           true_rank,
+           points
+       )
+       VALUES ($1, $2, $3, $4, $5, $6, $7)
+       RETURNING ranking_id
+      ",
+      &[
+        &creation_time,
+        &creator_user_id,
+        &guess_id,
+        &presentation_id,
+        &presentation_option_ids,
+        &true_rank,
+        &points,
+      ],
+    )
+    .await?
+    .get(0);
+
+  Ok(Ranking {
+    ranking_id,
+    creation_time,
+    creator_user_id,
+    guess_id,
+    presentation_id,
+    presentation_option_ids,
+    true_rank,
+    points,
+  })
+}
+
+pub async fn get_by_guess_id(
+  con: &mut impl GenericClient,
+  guess_id: i64,
+) -> Result<Option<Ranking>, tokio_postgres::Error> {
+  let result = con
+    .query_opt("SELECT * FROM ranking WHERE guess_id=$1", &[&guess_id])
+    .await?
+    .map(|x| x.into());
+
+  Ok(result)
+}
@@ -1,10 +1,50 @@
 use serde::{Deserialize, Serialize};
+use strum::AsRefStr;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GuestNewProps {}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GuestClaimNewProps {
+  pub guest_token: String,
+  pub api_key: String,
+}
+
+// every field is optional
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Bibliography {
+  pub authors: Option<Vec<String>>,
+  pub venue: Option<String>,
+  pub year: Option<i64>,
+  pub doi: Option<String>,
+  pub source_url: Option<String>,
+  pub abstract_text: Option<String>,
+  pub license: Option<String>,
+}
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ArticleNewProps {
   pub title: String,
   pub duration_estimate: i64,
+  pub bibliography: Option<Bibliography>,
+  pub api_key: String,
+}
+
+// sections is a grid of positions by variants, where variant 0 is the true completion
+// null cells are skipped, but every position needs a true completion
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArticleImportProps {
+  pub title: String,
+  pub duration_estimate: i64,
+  pub sections: Vec<Vec<Option<String>>>,
+  // the generator of each variant, the entry for variant 0 must be null
+  pub generator_ids: Option<Vec<Option<i64>>>,
+  pub bibliography: Option<Bibliography>,
   pub api_key: String,
 }
 
@@ -14,6 +54,8 @@ pub struct ArticleDataNewProps {
   pub article_id: i64,
   pub title: String,
   pub duration_estimate: i64,
+  // left as it was in the previous revision if not given
+  pub bibliography: Option<Bibliography>,
   pub active: bool,
   pub api_key: String,
 }
@@ -25,6 +67,7 @@ pub struct ArticleSectionNewProps {
   pub position: i64,
   pub variant: i64,
   pub section_text: String,
+  pub generator_id: Option<i64>,
   pub active: bool,
   pub api_key: String,
 }
@@ -52,6 +95,17 @@ pub struct ArticleDataViewProps {
   pub max_duration_estimate: Option<i64>,
   pub active: Option<bool>,
   pub only_recent: bool,
+  // only the revision of each article that was current at this time, takes precedence over only_recent
+  pub as_of: Option<i64>,
+  // matches articles with any of the given authors
+  pub author: Option<Vec<String>>,
+  pub venue: Option<Vec<String>>,
+  pub min_year: Option<i64>,
+  pub max_year: Option<i64>,
+  pub doi: Option<Vec<String>>,
+  pub license: Option<Vec<String>>,
+  // matches articles with any of the given tags of each kind, and with every kind given
+  pub tag: Option<Vec<ArticleTagFilter>>,
   pub api_key: String,
 }
 
@@ -65,8 +119,290 @@ pub struct ArticleSectionViewProps {
   pub article_id: Option<Vec<i64>>,
   pub position: Option<Vec<i64>>,
   pub variant: Option<Vec<i64>>,
+  pub generator_id: Option<Vec<i64>>,
   pub active: Option<bool>,
   pub only_recent: bool,
+  // only the revision of each section that was current at this time, takes precedence over only_recent
+  pub as_of: Option<i64>,
+  // matches sections of articles with any of the given tags of each kind, and with every kind given
+  pub tag: Option<Vec<ArticleTagFilter>>,
+  pub api_key: String,
+}
+
+// exactly one of time, article_data_id and article_section_id must be given
+// time restores the whole article to how it was then, sections added since are deactivated
+// article_data_id restores only the article's data, and article_section_id only that section
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArticleRevertProps {
+  pub article_id: i64,
+  pub time: Option<i64>,
+  pub article_data_id: Option<i64>,
+  pub article_section_id: Option<i64>,
+  pub api_key: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, AsRefStr)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+pub enum ArticleTagKind {
+  Field,
+  Difficulty,
+}
+
+// names are trimmed and lowercased, so Biology and biology are the same tag
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArticleTagNewProps {
+  pub article_id: i64,
+  pub kind: ArticleTagKind,
+  pub name: String,
+  pub active: bool,
+  pub api_key: String,
+}
+
+// names are matched the same way they're stored, trimmed and lowercased
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArticleTagFilter {
+  pub kind: ArticleTagKind,
+  pub name: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArticleTagViewProps {
+  pub article_tag_id: Option<Vec<i64>>,
+  pub min_creation_time: Option<i64>,
+  pub max_creation_time: Option<i64>,
+  pub creator_user_id: Option<Vec<i64>>,
+  pub article_id: Option<Vec<i64>>,
+  pub kind: Option<ArticleTagKind>,
+  pub name: Option<Vec<String>>,
+  pub active: Option<bool>,
+  pub only_recent: bool,
+  pub api_key: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GeneratorNewProps {
+  pub name: String,
+  pub version: String,
+  pub parameters: serde_json::Value,
+  pub api_key: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GeneratorViewProps {
+  pub generator_id: Option<Vec<i64>>,
+  pub min_creation_time: Option<i64>,
+  pub max_creation_time: Option<i64>,
+  pub creator_user_id: Option<Vec<i64>>,
+  pub name: Option<Vec<String>>,
+  pub version: Option<Vec<String>>,
+  pub api_key: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlaySessionNewProps {
+  pub article_id: i64,
+  // ignored when resuming a session
+  pub forced_choice: bool,
+  pub api_key: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlaySessionAbandonProps {
+  pub play_session_id: i64,
+  pub api_key: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlaySessionDataViewProps {
+  pub play_session_data_id: Option<Vec<i64>>,
+  pub min_creation_time: Option<i64>,
+  pub max_creation_time: Option<i64>,
+  pub creator_user_id: Option<Vec<i64>>,
+  pub play_session_id: Option<Vec<i64>>,
+  pub article_id: Option<Vec<i64>>,
+  pub finished: Option<bool>,
+  pub abandoned: Option<bool>,
+  pub only_recent: bool,
+  pub api_key: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PresentationNewProps {
+  pub play_session_id: i64,
+  pub api_key: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GuessNewProps {
+  pub presentation_option_id: i64,
+  // probability that the pick is the true completion, from 1 / option count up to 1
+  pub confidence: Option<f64>,
+  pub api_key: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RankingNewProps {
+  // every option of the presentation, most likely real first
+  pub presentation_option_ids: Vec<i64>,
+  pub api_key: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, AsRefStr)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+pub enum AnnotationReason {
+  FactualError,
+  Repetition,
+  Style,
+}
+
+// offsets count unicode characters of the section_text, end exclusive
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AnnotationNewProps {
+  pub guess_id: i64,
+  pub article_section_id: i64,
+  pub start_offset: i64,
+  pub end_offset: i64,
+  pub reason: Option<AnnotationReason>,
+  pub api_key: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AnnotationHeatmapProps {
+  pub article_section_id: i64,
+  pub api_key: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GuessViewProps {
+  pub guess_id: Option<Vec<i64>>,
+  pub min_creation_time: Option<i64>,
+  pub max_creation_time: Option<i64>,
+  pub creator_user_id: Option<Vec<i64>>,
+  pub article_id: Option<Vec<i64>>,
+  pub position: Option<Vec<i64>>,
+  pub presentation_id: Option<Vec<i64>>,
+  pub article_section_id: Option<Vec<i64>>,
+  pub correct: Option<bool>,
+  pub api_key: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GeneratorLeaderboardProps {
+  pub api_key: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArticleSectionStatsProps {
+  pub article_id: i64,
+  pub position: Option<Vec<i64>>,
+  pub api_key: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArticleLatencyStatsProps {
+  pub article_id: i64,
+  pub api_key: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArticleBibliographyViewProps {
+  pub article_id: i64,
+  pub api_key: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArticleHistoryViewProps {
+  pub article_id: i64,
+  pub api_key: String,
+}
+
+// both revisions must belong to the same article
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArticleDataDiffProps {
+  pub from_article_data_id: i64,
+  pub to_article_data_id: i64,
+  pub api_key: String,
+}
+
+// both revisions must be of the same position and variant of the same article
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArticleSectionDiffProps {
+  pub from_article_section_id: i64,
+  pub to_article_section_id: i64,
+  pub api_key: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserStatsViewProps {
+  pub api_key: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum LeaderboardKind {
+  AllTime,
+  Weekly,
+  Article,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LeaderboardViewProps {
+  pub kind: LeaderboardKind,
+  // required for article leaderboards
+  pub article_id: Option<i64>,
+  pub api_key: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PlayNextStrategy {
+  Random,
+  LeastPlayed,
+  Newest,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlayNextProps {
+  pub strategy: PlayNextStrategy,
+  pub api_key: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DailyChallengeViewProps {
+  pub api_key: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DailyChallengePlayProps {
   pub api_key: String,
 }
 
@@ -83,6 +419,7 @@ pub struct ArticleDataViewPublicProps {
   pub title: Option<Vec<String>>,
   pub min_duration_estimate: Option<i64>,
   pub max_duration_estimate: Option<i64>,
+  pub tag: Option<Vec<ArticleTagFilter>>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -95,5 +432,20 @@ pub struct ArticleSectionViewPublicProps {
   pub article_id: Option<Vec<i64>>,
   pub position: Option<Vec<i64>>,
   pub variant: Option<Vec<i64>>,
+  pub tag: Option<Vec<ArticleTagFilter>>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArticleTagViewPublicProps {
+  pub article_id: Option<Vec<i64>>,
+  pub kind: Option<ArticleTagKind>,
+  pub name: Option<Vec<String>>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArticleTagCountPublicProps {
+  pub kind: Option<ArticleTagKind>,
 }
 
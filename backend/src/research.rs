@@ -0,0 +1,169 @@
+// Exports the guesses made on a set of articles for offline analysis, as csv or jsonl.
+// Players and play sessions are replaced with pseudonyms: the hex sha256 of a random salt and the id.
+// The salt is drawn fresh for every export and never stored, so a pseudonym is stable within one export,
+// but can't be joined back to a user id, nor to the pseudonyms of another export.
+//
+// Each guess has the columns
+//   player, session, forcedChoice, articleId, position, variantsShown, variantChosen, generatorId, correct, latency, confidence
+// variantsShown lists the variants of every option the player was shown, ascending (space separated in csv)
+// generatorId is the generator of the chosen variant, empty when the true completion (variant 0) was chosen
+// latency is the milliseconds the server measured between serving the options and receiving the guess
+
+use clap::{Args, ValueEnum};
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::error::Error;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::PathBuf;
+
+use super::guess_record_service;
+use super::import;
+
+#[derive(ValueEnum, Clone, Copy)]
+pub enum ExportFormat {
+    Csv,
+    Jsonl,
+}
+
+#[derive(Args, Clone)]
+pub struct ExportGuessesOpts {
+    #[clap(long)]
+    database_url: String,
+    /// may be given more than once
+    #[clap(long, required = true)]
+    article_id: Vec<i64>,
+    #[clap(long, value_enum)]
+    format: ExportFormat,
+    #[clap(long)]
+    file: PathBuf,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GuessExportRecord {
+    pub player: String,
+    pub session: String,
+    pub forced_choice: bool,
+    pub article_id: i64,
+    pub position: i64,
+    pub variants_shown: Vec<i64>,
+    pub variant_chosen: i64,
+    pub generator_id: Option<i64>,
+    pub correct: bool,
+    pub latency: i64,
+    pub confidence: Option<f64>,
+}
+
+static CSV_HEADER: [&str; 11] = [
+    "player",
+    "session",
+    "forcedChoice",
+    "articleId",
+    "position",
+    "variantsShown",
+    "variantChosen",
+    "generatorId",
+    "correct",
+    "latency",
+    "confidence",
+];
+
+struct Pseudonymizer {
+    salt: [u8; 32],
+}
+
+impl Pseudonymizer {
+    fn new() -> Pseudonymizer {
+        let mut salt = [0; 32];
+        rand::thread_rng().fill_bytes(&mut salt);
+        Pseudonymizer { salt }
+    }
+
+    // kind keeps a player and a session with the same id from sharing a pseudonym
+    fn pseudonym(&self, kind: &str, id: i64) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(self.salt);
+        hasher.update(kind.as_bytes());
+        hasher.update(id.to_be_bytes());
+        hex::encode(hasher.finalize())
+    }
+}
+
+fn csv_row(record: &GuessExportRecord) -> Vec<String> {
+    vec![
+        record.player.clone(),
+        record.session.clone(),
+        record.forced_choice.to_string(),
+        record.article_id.to_string(),
+        record.position.to_string(),
+        record
+            .variants_shown
+            .iter()
+            .map(|x| x.to_string())
+            .collect::<Vec<_>>()
+            .join(" "),
+        record.variant_chosen.to_string(),
+        record
+            .generator_id
+            .map(|x| x.to_string())
+            .unwrap_or_default(),
+        record.correct.to_string(),
+        record.latency.to_string(),
+        record.confidence.map(|x| x.to_string()).unwrap_or_default(),
+    ]
+}
+
+pub async fn export_guesses(opts: ExportGuessesOpts) -> Result<(), Box<dyn Error>> {
+    let mut client = import::connect(&opts.database_url).await?;
+
+    let guess_records =
+        guess_record_service::get_by_article_ids(&mut client, &opts.article_id).await?;
+
+    let pseudonymizer = Pseudonymizer::new();
+
+    let records: Vec<GuessExportRecord> = guess_records
+        .into_iter()
+        .map(|x| GuessExportRecord {
+            player: pseudonymizer.pseudonym("player", x.creator_user_id),
+            session: pseudonymizer.pseudonym("session", x.play_session_id),
+            forced_choice: x.forced_choice,
+            article_id: x.article_id,
+            position: x.position,
+            variants_shown: x.variants_shown,
+            variant_chosen: x.variant_chosen,
+            generator_id: x.generator_id,
+            correct: x.correct,
+            latency: x.latency,
+            confidence: x.confidence,
+        })
+        .collect();
+
+    match opts.format {
+        ExportFormat::Csv => {
+            let mut writer = csv::Writer::from_path(&opts.file)?;
+            writer.write_record(CSV_HEADER)?;
+            for record in &records {
+                writer.write_record(csv_row(record))?;
+            }
+            writer.flush()?;
+        }
+        ExportFormat::Jsonl => {
+            let mut writer = BufWriter::new(File::create(&opts.file)?);
+            for record in &records {
+                serde_json::to_writer(&mut writer, record)?;
+                writeln!(writer)?;
+            }
+            writer.flush()?;
+        }
+    }
+
+    println!(
+        "exported {} guesses to {}",
+        records.len(),
+        opts.file.display()
+    );
+
+    Ok(())
+}
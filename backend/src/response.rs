@@ -5,10 +5,33 @@ use strum::AsRefStr;
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum AppError {
     NoCapability,
+    GuestNonexistent,
+    GuestAlreadyClaimed,
+    GuestsDisabled,
     ArticleNonexistent,
+    ArticleDataNonexistent,
+    ArticleNotFinished,
     ArticleSectionNonexistent,
+    GeneratorNonexistent,
+    PlaySessionNonexistent,
+    PlaySessionClosed,
+    DailyChallengeNonexistent,
+    DailyChallengeAlreadyAttempted,
+    DailyChallengeNotFinished,
+    PresentationNonexistent,
+    PresentationOutdated,
+    PresentationOptionNonexistent,
+    PresentationAlreadyGuessed,
+    InvalidRanking,
+    InvalidConfidence,
+    GuessNonexistent,
+    InvalidAnnotation,
     InvalidDuration,
     InvalidPosition,
+    InvalidArticleImport,
+    InvalidRevision,
+    DiffTooLarge,
+    InvalidTag,
     DecodeError,
     InternalServerError,
     MethodNotAllowed,
@@ -27,6 +50,28 @@ impl std::fmt::Display for AppError {
 
 impl std::error::Error for AppError {}
 
+// guest_token is passed in place of an api key
+// user_id is what the guest's gameplay and content is attributed to
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Guest {
+    pub guest_id: i64,
+    pub creation_time: i64,
+    pub user_id: i64,
+    pub guest_token: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GuestClaim {
+    pub guest_claim_id: i64,
+    pub creation_time: i64,
+    pub creator_user_id: i64,
+    pub guest_id: i64,
+    // the user_id the guest's history was attributed to
+    pub guest_user_id: i64,
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Article {
@@ -47,6 +92,30 @@ pub struct ArticleData {
     pub active: bool,
 }
 
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Generator {
+    pub generator_id: i64,
+    pub creation_time: i64,
+    pub creator_user_id: i64,
+    pub name: String,
+    pub version: String,
+    pub parameters: serde_json::Value,
+}
+
+// creation_time is when the rating last changed, and guess_id is the guess that changed it
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GeneratorRating {
+    pub generator_rating_id: i64,
+    pub creation_time: i64,
+    pub generator: Generator,
+    pub guess_id: i64,
+    pub rating: f64,
+    pub win_count: i64,
+    pub loss_count: i64,
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ArticleSection {
@@ -57,9 +126,359 @@ pub struct ArticleSection {
     pub position: i64,
     pub variant: i64,
     pub section_text: String,
+    pub generator: Option<Generator>,
     pub active: bool,
 }
 
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArticleTag {
+    pub article_tag_id: i64,
+    pub creation_time: i64,
+    pub creator_user_id: i64,
+    pub article: Article,
+    pub kind: String,
+    pub name: String,
+    pub active: bool,
+}
+
+// the number of active articles carrying a tag
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArticleTagCount {
+    pub kind: String,
+    pub name: String,
+    pub article_count: i64,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Bibliography {
+    pub authors: Vec<String>,
+    pub venue: Option<String>,
+    pub year: Option<i64>,
+    pub doi: Option<String>,
+    pub source_url: Option<String>,
+    pub abstract_text: Option<String>,
+    pub license: Option<String>,
+}
+
+// kept out of ArticleData, since it would give away where the true completions came from
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArticleBibliography {
+    pub article_data: ArticleData,
+    pub bibliography: Bibliography,
+}
+
+// revisions made by the same user in one sitting
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArticleEdit {
+    pub start_time: i64,
+    pub end_time: i64,
+    pub creator_user_id: i64,
+    pub article_data: Vec<ArticleData>,
+    pub article_sections: Vec<ArticleSection>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArticleHistory {
+    pub article: Article,
+    // oldest first
+    pub edits: Vec<ArticleEdit>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum DiffKind {
+    Equal,
+    Insert,
+    Delete,
+}
+
+// the chunks of a diff join back into the old text when insertions are skipped,
+// and into the new text when deletions are skipped
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiffChunk {
+    pub kind: DiffKind,
+    pub text: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArticleDataDiff {
+    pub from: ArticleData,
+    pub to: ArticleData,
+    pub title: Vec<DiffChunk>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArticleSectionDiff {
+    pub from: ArticleSection,
+    pub to: ArticleSection,
+    pub section_text: Vec<DiffChunk>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlaySession {
+    pub play_session_id: i64,
+    pub creation_time: i64,
+    pub creator_user_id: i64,
+    pub article: Article,
+    pub daily_challenge_id: Option<i64>,
+    pub forced_choice: bool,
+    // false for replays, which don't count towards leaderboards or ratings
+    pub scored: bool,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlaySessionData {
+    pub play_session_data_id: i64,
+    pub creation_time: i64,
+    pub creator_user_id: i64,
+    pub play_session: PlaySession,
+    pub position: i64,
+    pub finish_time: Option<i64>,
+    pub abandon_time: Option<i64>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DailyChallengeResult {
+    pub correct_count: i64,
+    pub guess_count: i64,
+    pub points: i64,
+    // doesn't give away the article or any of its sections
+    pub share_text: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DailyChallenge {
+    pub daily_challenge_id: i64,
+    pub creation_time: i64,
+    pub day: i64,
+    pub article_data: ArticleData,
+    // the caller's attempt, none until they start it
+    pub play_session_data: Option<PlaySessionData>,
+    // none until the attempt is finished
+    pub result: Option<DailyChallengeResult>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PositionDifficulty {
+    pub position_difficulty_id: i64,
+    pub creation_time: i64,
+    pub article: Article,
+    pub position: i64,
+    pub guess_id: i64,
+    pub rating: f64,
+    pub guess_count: i64,
+}
+
+// the variant is deliberately left out, so that it can't be used to find the true completion
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PresentationOption {
+    pub presentation_option_id: i64,
+    pub section_text: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Presentation {
+    pub presentation_id: i64,
+    pub creation_time: i64,
+    pub creator_user_id: i64,
+    pub play_session_id: i64,
+    pub article: Article,
+    pub position: i64,
+    // the true completions of the positions the player has already answered
+    pub context: Vec<String>,
+    pub options: Vec<PresentationOption>,
+    // none until someone has guessed on this position
+    pub difficulty: Option<PositionDifficulty>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Guess {
+    pub guess_id: i64,
+    pub creation_time: i64,
+    pub creator_user_id: i64,
+    pub article: Article,
+    pub position: i64,
+    pub presentation_id: i64,
+    pub presentation_option_id: i64,
+    pub article_section_id: i64,
+    pub correct: bool,
+    pub points: i64,
+    pub latency: i64,
+    pub confidence: Option<f64>,
+    pub brier_score: Option<f64>,
+    pub solution_presentation_option_id: Option<i64>,
+    // set if the guess is the first option of a ranking
+    pub ranking_id: Option<i64>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Annotation {
+    pub annotation_id: i64,
+    pub creation_time: i64,
+    pub creator_user_id: i64,
+    pub guess_id: i64,
+    pub article_section: ArticleSection,
+    pub start_offset: i64,
+    pub end_offset: i64,
+    pub reason: Option<String>,
+}
+
+// a run of characters that were covered by the same number of annotations
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AnnotationHeatmapSpan {
+    pub start_offset: i64,
+    pub end_offset: i64,
+    pub count: i64,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AnnotationReasonCount {
+    // none for annotations made without a reason
+    pub reason: Option<String>,
+    pub count: i64,
+}
+
+// spans with no annotations are left out
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AnnotationHeatmap {
+    pub article_section: ArticleSection,
+    pub annotation_count: i64,
+    pub spans: Vec<AnnotationHeatmapSpan>,
+    pub reasons: Vec<AnnotationReasonCount>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Ranking {
+    pub ranking_id: i64,
+    pub creation_time: i64,
+    pub creator_user_id: i64,
+    pub presentation_id: i64,
+    // most likely real first
+    pub presentation_option_ids: Vec<i64>,
+    // zero based, none if the true completion wasn't an option
+    pub true_rank: Option<i64>,
+    // one point per fake ranked below the true completion, kept off the leaderboard
+    pub points: i64,
+    // the guess recorded for the first option of the ranking
+    pub guess: Guess,
+}
+
+// for fake variants, pick_rate is how often players were fooled by it
+// for the true variant, it's how often players got the position right
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArticleSectionStats {
+    pub article: Article,
+    pub position: i64,
+    pub variant: i64,
+    pub generator: Option<Generator>,
+    pub shown_count: i64,
+    pub pick_count: i64,
+    pub pick_rate: f64,
+    // 95% confidence interval for pick_rate
+    pub pick_rate_low: f64,
+    pub pick_rate_high: f64,
+}
+
+// latencies are in milliseconds, and are none when there are no guesses to summarize
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LatencyStats {
+    pub guess_count: i64,
+    pub mean_latency: Option<f64>,
+    pub median_latency: Option<f64>,
+    pub p90_latency: Option<f64>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PositionLatencyStats {
+    pub position: i64,
+    pub latency: LatencyStats,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArticleLatencyStats {
+    pub article: Article,
+    pub latency: LatencyStats,
+    pub positions: Vec<PositionLatencyStats>,
+}
+
+// a well calibrated player's accuracy in each bin is close to their mean_confidence
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CalibrationBin {
+    pub min_confidence: f64,
+    pub max_confidence: f64,
+    pub guess_count: i64,
+    pub correct_count: i64,
+    pub accuracy: f64,
+    pub mean_confidence: f64,
+    pub mean_brier_score: f64,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserRating {
+    pub user_rating_id: i64,
+    pub creation_time: i64,
+    pub creator_user_id: i64,
+    pub guess_id: i64,
+    pub rating: f64,
+    pub guess_count: i64,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserStats {
+    pub creator_user_id: i64,
+    pub rating: f64,
+    pub guess_count: i64,
+    // oldest first
+    pub rating_history: Vec<UserRating>,
+    pub latency: LatencyStats,
+    // only covers guesses made with a confidence, none if there are none
+    pub mean_brier_score: Option<f64>,
+    pub calibration: Vec<CalibrationBin>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LeaderboardEntry {
+    pub rank: i64,
+    pub creator_user_id: i64,
+    // none for guests, and for everyone when a guest is viewing
+    pub username: Option<String>,
+    // an unclaimed guest, shown without a username
+    pub guest: bool,
+    pub points: i64,
+    pub correct_count: i64,
+    pub guess_count: i64,
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Info {
@@ -0,0 +1,129 @@
+// z score for a two sided 95% confidence interval
+static Z_95: f64 = 1.959964;
+
+pub static ELO_INITIAL_RATING: f64 = 1500.0;
+static ELO_K: f64 = 32.0;
+
+// Wilson score interval for a binomial proportion
+// behaves much better than the normal approximation when counts are small or rates are near 0 or 1
+pub fn wilson_interval(successes: i64, trials: i64) -> (f64, f64) {
+  if trials == 0 {
+    return (0.0, 1.0);
+  }
+
+  let n = trials as f64;
+  let p = successes as f64 / n;
+  let z2 = Z_95 * Z_95;
+
+  let center = (p + z2 / (2.0 * n)) / (1.0 + z2 / n);
+  let margin = Z_95 * (p * (1.0 - p) / n + z2 / (4.0 * n * n)).sqrt() / (1.0 + z2 / n);
+
+  ((center - margin).max(0.0), (center + margin).min(1.0))
+}
+
+// probability that a player with the given rating beats the opponent
+pub fn elo_expected(rating: f64, opponent_rating: f64) -> f64 {
+  1.0 / (1.0 + 10f64.powf((opponent_rating - rating) / 400.0))
+}
+
+// score is 1.0 for a win and 0.0 for a loss
+pub fn elo_update(rating: f64, opponent_rating: f64, score: f64) -> f64 {
+  rating + ELO_K * (score - elo_expected(rating, opponent_rating))
+}
+
+// squared error of the stated probability that the pick was right, 0 is perfect and 1 is confidently wrong
+pub fn brier_score(confidence: f64, correct: bool) -> f64 {
+  let outcome = if correct { 1.0 } else { 0.0 };
+  (confidence - outcome) * (confidence - outcome)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn assert_close(actual: f64, expected: f64) {
+    assert!(
+      (actual - expected).abs() < 1e-4,
+      "{} != {}",
+      actual,
+      expected
+    );
+  }
+
+  #[test]
+  fn wilson_interval_without_trials_is_uninformative() {
+    assert_eq!(wilson_interval(0, 0), (0.0, 1.0));
+  }
+
+  #[test]
+  fn wilson_interval_known_value() {
+    let (low, high) = wilson_interval(5, 10);
+    assert_close(low, 0.2366);
+    assert_close(high, 0.7634);
+  }
+
+  #[test]
+  fn wilson_interval_at_the_extremes() {
+    let (low, high) = wilson_interval(0, 10);
+    assert_close(low, 0.0);
+    assert!(high > 0.0 && high < 1.0);
+
+    let (low, high) = wilson_interval(10, 10);
+    assert!(low > 0.0 && low < 1.0);
+    assert_close(high, 1.0);
+  }
+
+  #[test]
+  fn wilson_interval_narrows_with_more_trials() {
+    for (successes, trials) in [(1, 3), (7, 20), (70, 200), (700, 2000)] {
+      let (low, high) = wilson_interval(successes, trials);
+      let rate = successes as f64 / trials as f64;
+      assert!(0.0 <= low && low <= rate && rate <= high && high <= 1.0);
+    }
+    let (small_low, small_high) = wilson_interval(7, 20);
+    let (large_low, large_high) = wilson_interval(700, 2000);
+    assert!(large_high - large_low < small_high - small_low);
+  }
+
+  #[test]
+  fn elo_even_match() {
+    assert_close(elo_expected(1500.0, 1500.0), 0.5);
+    assert_close(elo_update(1500.0, 1500.0, 1.0), 1516.0);
+    assert_close(elo_update(1500.0, 1500.0, 0.0), 1484.0);
+  }
+
+  #[test]
+  fn elo_favourite_gains_little() {
+    // a 400 point gap makes the favourite ten times as likely to win
+    assert_close(elo_expected(1900.0, 1500.0), 10.0 / 11.0);
+    assert_close(elo_update(1900.0, 1500.0, 1.0), 1900.0 + 32.0 / 11.0);
+    assert_close(elo_update(1500.0, 1900.0, 1.0), 1500.0 + 320.0 / 11.0);
+  }
+
+  #[test]
+  fn elo_is_zero_sum() {
+    for (rating, opponent_rating) in [(1500.0, 1500.0), (1720.0, 1380.0), (1200.0, 2000.0)] {
+      for score in [0.0, 1.0] {
+        let gain = elo_update(rating, opponent_rating, score) - rating;
+        let loss = elo_update(opponent_rating, rating, 1.0 - score) - opponent_rating;
+        assert_close(gain, -loss);
+      }
+    }
+  }
+
+  #[test]
+  fn brier_score_extremes() {
+    assert_close(brier_score(1.0, true), 0.0);
+    assert_close(brier_score(1.0, false), 1.0);
+    assert_close(brier_score(0.5, true), 0.25);
+    assert_close(brier_score(0.5, false), 0.25);
+  }
+
+  #[test]
+  fn brier_score_rewards_confidence_only_when_right() {
+    assert!(brier_score(0.9, true) < brier_score(0.6, true));
+    assert!(brier_score(0.9, false) > brier_score(0.6, false));
+    assert_close(brier_score(0.8, true), 0.04);
+    assert_close(brier_score(0.8, false), 0.64);
+  }
+}
@@ -0,0 +1,97 @@
+use super::db_types::*;
+use super::utils::current_time_millis;
+use tokio_postgres::GenericClient;
+
+impl From<tokio_postgres::row::Row> for UserRating {
+  // select * from user_rating order only, otherwise it will fail
+  fn from(row: tokio_postgres::Row) -> UserRating {
+    UserRating {
+      user_rating_id: row.get("user_rating_id"),
+      creation_time: row.get("creation_time"),
+      creator_user_id: row.get("creator_user_id"),
+      guess_id: row.get("guess_id"),
+      rating: row.get("rating"),
+      guess_count: row.get("guess_count"),
+    }
+  }
+}
+
+pub async fn add(
+  con: &mut impl GenericClient,
+  creator_user_id: i64,
+  guess_id: i64,
+  rating: f64,
+  guess_count: i64,
+) -> Result<UserRating, tokio_postgres::Error> {
+  let creation_time = current_time_millis();
+
+  let user_rating_id = con
+    .query_one(
+      "INSERT INTO
+       user_rating(
+           creation_time,
+           creator_user_id,
+           guess_id,
+           rating,
+           guess_count
+       )
+       VALUES ($1, $2, $3, $4, $5)
+       RETURNING user_rating_id
+      ",
+      &[
+        &creation_time,
+        &creator_user_id,
+        &guess_id,
+        &rating,
+        &guess_count,
+      ],
+    )
+    .await?
+    .get(0);
+
+  Ok(UserRating {
+    user_rating_id,
+    creation_time,
+    creator_user_id,
+    guess_id,
+    rating,
+    guess_count,
+  })
+}
+
+// a user who claimed guests continues from whichever of their ratings changed last
+pub async fn get_recent_by_creator_user_ids(
+  con: &mut impl GenericClient,
+  creator_user_ids: &[i64],
+) -> Result<Option<UserRating>, tokio_postgres::Error> {
+  let result = con
+    .query_opt(
+      "SELECT * FROM recent_user_rating WHERE creator_user_id = ANY($1)
+       ORDER BY user_rating_id DESC
+       LIMIT 1",
+      &[&creator_user_ids],
+    )
+    .await?
+    .map(|x| x.into());
+
+  Ok(result)
+}
+
+// every rating the user has had, oldest first
+pub async fn get_by_creator_user_ids(
+  con: &mut impl GenericClient,
+  creator_user_ids: &[i64],
+) -> Result<Vec<UserRating>, tokio_postgres::Error> {
+  let results = con
+    .query(
+      "SELECT * FROM user_rating WHERE creator_user_id = ANY($1)
+       ORDER BY user_rating_id",
+      &[&creator_user_ids],
+    )
+    .await?
+    .into_iter()
+    .map(|x| x.into())
+    .collect();
+
+  Ok(results)
+}
@@ -2,84 +2,81 @@ import React from 'react';
 import { Button, Container, Card, Spinner } from 'react-bootstrap';
 import { Async, AsyncProps } from 'react-async';
 import update from 'immutability-helper';
-import { Section, BrandedComponentProps } from '@innexgo/common-react-components';
+import { BrandedComponentProps } from '@innexgo/common-react-components';
 import ErrorMessage from '../components/ErrorMessage';
 import ExternalLayout from '../components/ExternalLayout';
 
-import { ArticleData, ArticleSection, articleDataViewPublic, articleSectionViewPublic } from '../utils/api';
+import { ArticleData, Guess, Presentation, PresentationOption, articleDataViewPublic, guestNew, playSessionNew, presentationNew, guessNew } from '../utils/api';
 import { unwrap, getFirstOr } from '@innexgo/frontend-common';
-import format from 'date-fns/format';
-import formatDistance from 'date-fns/formatDistance';
 import { useSearchParams } from 'react-router-dom'
 import { animated, Controller } from '@react-spring/web'
 
-type SelectedSection = {
-  section: ArticleSection,
-  marked: boolean
-  selected: boolean
+// players without an account play as a guest, whose token stands in for an api key
+const GUEST_TOKEN_KEY = "critica-guest-token";
+
+const getApiKey = async () => {
+  const guestToken = window.localStorage.getItem(GUEST_TOKEN_KEY);
+  if (guestToken !== null) {
+    return guestToken;
+  }
+  const guest = await guestNew().then(unwrap);
+  window.localStorage.setItem(GUEST_TOKEN_KEY, guest.guestToken);
+  return guest.guestToken;
 }
 
 type ManageArticleSectionOptionProps = {
-  articleData: ArticleData,
-  section: SelectedSection,
-  setSection: (s: SelectedSection) => void
+  option: PresentationOption,
+  // set once the player has guessed
+  guess?: Guess,
+  disabled: boolean,
+  onChoose: () => void
 };
 
 class ManageArticleSectionOption extends React.Component<ManageArticleSectionOptionProps, {}>  {
 
-  private controller: Controller<{ opacity: number }> | Controller<{ translateX: number }>;
+  private controller: Controller<{ translateX: number }>;
 
   constructor(props: ManageArticleSectionOptionProps) {
     super(props);
-    if (props.section.section.variant === 0) {
-      this.controller = new Controller({
-        from: { opacity: 1 },
-        to: { opacity: 0 }
-      });
-    } else {
-      this.controller = new Controller({
-        config: {
-          frequency: 0.1,
-          damping: 0.1
-        },
-        from: { translateX: -5 },
-        to: { translateX: 5 },
-      })
-    }
+    this.controller = new Controller({
+      config: {
+        frequency: 0.1,
+        damping: 0.1
+      },
+      from: { translateX: -5 },
+      to: { translateX: 5 },
+    })
     this.controller.pause();
   }
 
   componentDidUpdate() {
-    if (this.props.section.marked) {
+    // shake the pick if it was a fake
+    if (this.props.guess?.presentationOptionId === this.props.option.presentationOptionId && !this.props.guess.correct) {
       this.controller.resume();
-      setTimeout(() => {
-        this.props.setSection(update(this.props.section, { selected: { $set: true } }))
-      }, 1000);
     }
   }
 
   render() {
+    const guess = this.props.guess;
+    const border =
+      guess === undefined
+        ? undefined
+        : guess.solutionPresentationOptionId === this.props.option.presentationOptionId
+          ? "success"
+          : guess.presentationOptionId === this.props.option.presentationOptionId
+            ? "danger"
+            : undefined;
+
     return <animated.div style={this.controller.springs} className="col-xl p-3" >
-      <Card className="w-100"
-        border={
-          this.props.section.marked
-            ? this.props.section.section.variant === 0
-              ? "success"
-              : "danger"
-            : undefined
-        }
-      >
+      <Card className="w-100" border={border}>
         <Card.Body>
           <Card.Text>
-            {this.props.section.section.sectionText}
+            {this.props.option.sectionText}
           </Card.Text>
           <Button
             variant="primary"
-            disabled={this.props.section.marked}
-            onClick={() =>
-              // we mark it, so the animation can run. the animation (once done) selects it
-              this.props.setSection(update(this.props.section, { marked: { $set: true } }))
-            }
+            disabled={this.props.disabled}
+            onClick={this.props.onChoose}
           >
             Choose
           </Button>
@@ -91,27 +88,19 @@ class ManageArticleSectionOption extends React.Component<ManageArticleSectionOpt
 
 type ManageArticleSectionOptionsProps = {
   articleData: ArticleData,
-  position: number,
-  sections: SelectedSection[],
-  setSection: (i: number, s: SelectedSection) => void
+  // none once the article is finished
+  presentation?: Presentation,
+  guess?: Guess,
+  onChoose: (option: PresentationOption) => void
 };
 
-
 function ManageArticleSectionOptions(props: ManageArticleSectionOptionsProps) {
-  const options = props.sections
-    .map((section, originalId) => ({ section, originalId }))
-    .filter(s => s.section.section.position === props.position + 1)
-    .sort((a, b) => a.section.section.sectionText.localeCompare(b.section.section.sectionText));
-
-  const previousSelections = props.sections
-    .filter(s => s.section.variant === 0 && s.section.position <= props.position);
-
-  const finished = options.length === 0;
+  const finished = props.presentation === undefined;
 
   return <div>
     <div style={{ maxWidth: "50rem" }} className="mx-auto">
       <h2>{props.articleData.title}</h2>
-      {previousSelections.map((s, i) => <p key={i} children={s.section.sectionText} />)}
+      {props.presentation?.context.map((s, i) => <p key={i} children={s} />)}
     </div>
     <h5 className="pt-5" hidden={finished}>
       Pick the true completion of the article:
@@ -122,25 +111,39 @@ function ManageArticleSectionOptions(props: ManageArticleSectionOptionsProps) {
       </h4>
     </div>
     <div className="row px-5">
-      {options.map(s =>
+      {props.presentation?.options.map(option =>
         <ManageArticleSectionOption
-          key={s.originalId}
-          articleData={props.articleData}
-          section={s.section}
-          setSection={sec => props.setSection(s.originalId, sec)}
+          key={option.presentationOptionId}
+          option={option}
+          guess={props.guess}
+          disabled={props.guess !== undefined}
+          onChoose={() => props.onChoose(option)}
         />
       )}
     </div>
   </div>
 }
 
-
 type Data = {
+  apiKey: string,
   articleData: ArticleData,
-  sectionData: SelectedSection[],
+  playSessionId: number,
+  presentation?: Presentation,
+  guess?: Guess,
 }
 
-const loadData = async (props: AsyncProps<Data>) => {
+// the next position of the session, or none once the session is over
+const loadPresentation = async (apiKey: string, playSessionId: number) => {
+  const presentation = await presentationNew({ playSessionId, apiKey });
+  if ("Err" in presentation && presentation.Err === "PLAY_SESSION_CLOSED") {
+    return undefined;
+  }
+  return unwrap(presentation);
+}
+
+const loadData = async (props: AsyncProps<Data>): Promise<Data> => {
+  const apiKey = await getApiKey();
+
   const articleData =
     await articleDataViewPublic({
       articleId: [props.articleId],
@@ -149,44 +152,33 @@ const loadData = async (props: AsyncProps<Data>) => {
       .then(x => getFirstOr(x, "NOT_FOUND"))
       .then(unwrap);
 
-  const articleSection =
-    await articleSectionViewPublic({
-      articleId: [props.articleId],
+  // resumes the session if the player already has one going
+  const playSessionData =
+    await playSessionNew({
+      articleId: props.articleId,
+      forcedChoice: false,
+      apiKey,
     })
       .then(unwrap);
 
+  const playSessionId = playSessionData.playSession.playSessionId;
+
   return {
+    apiKey,
     articleData,
-    sectionData: articleSection.map(s => ({
-      section: s,
-      marked: false,
-      selected: false,
-    }))
+    playSessionId,
+    presentation: await loadPresentation(apiKey, playSessionId),
   }
 }
 
 function ArticleView(props: BrandedComponentProps) {
-  const [searchParams, setSearchParams] = useSearchParams();
-  const initialPosition = parseInt(searchParams.get("position") ?? "", 10) || 0;
-  const [position, raw_setPosition] = React.useState(initialPosition);
+  const [searchParams] = useSearchParams();
   const articleId = parseInt(searchParams.get("articleId") ?? "", 10);
-  const setPosition = (n: number) => {
-    setSearchParams(
-      {
-        articleId: articleId.toString(),
-        position: n.toString(),
-      },
-      {
-        replace: true
-      }
-    );
-    raw_setPosition(n);
-  }
 
   return <ExternalLayout branding={props.branding} fixed={false} transparentTop={true}>
     <Container className="py-4">
       <Async promiseFn={loadData} articleId={articleId}>
-        {({ setData }) => <>
+        {({ setData, setError }) => <>
           <Async.Pending>
             <Spinner animation="border" role="status">
               <span className="visually-hidden">Loading...</span>
@@ -196,15 +188,28 @@ function ArticleView(props: BrandedComponentProps) {
             {e => <ErrorMessage error={e} />}
           </Async.Rejected>
           <Async.Fulfilled<Data>>{d => <ManageArticleSectionOptions
-            key={position}
+            key={d.presentation?.presentationId}
             articleData={d.articleData}
-            position={position}
-            sections={d.sectionData}
-            setSection={(i, s) => {
-              if (s.section.variant === 0 && s.selected) {
-                setPosition(position + 1);
+            presentation={d.presentation}
+            guess={d.guess}
+            onChoose={async option => {
+              try {
+                const guess = await guessNew({
+                  presentationOptionId: option.presentationOptionId,
+                  apiKey: d.apiKey,
+                })
+                  .then(unwrap);
+                const guessed = update(d, { guess: { $set: guess } });
+                setData(guessed);
+                // leave the answer up for a moment before moving on
+                const presentation = await loadPresentation(d.apiKey, d.playSessionId);
+                setTimeout(() => setData(update(guessed, {
+                  presentation: { $set: presentation },
+                  guess: { $set: undefined },
+                })), 1000);
+              } catch (e) {
+                setError(e as Error);
               }
-              setData(update(d, { sectionData: { [i]: { $set: s } } }));
             }}
           />}
           </Async.Fulfilled>
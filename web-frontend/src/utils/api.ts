@@ -11,13 +11,13 @@ export interface Info {
   authAuthenticatorHref: string,
 }
 
-export interface Article {
-  articleId: number,
+export interface Guest {
+  guestId: number,
   creationTime: number,
-  creatorUserId: number,
+  userId: number,
+  guestToken: string,
 }
 
-
 export interface Article {
   articleId: number,
   creationTime: number,
@@ -45,12 +45,90 @@ export interface ArticleSection {
   active: boolean,
 }
 
+export interface PlaySession {
+  playSessionId: number,
+  creationTime: number,
+  creatorUserId: number,
+  article: Article,
+  dailyChallengeId?: number,
+  forcedChoice: boolean,
+  scored: boolean,
+}
+
+export interface PlaySessionData {
+  playSessionDataId: number,
+  creationTime: number,
+  creatorUserId: number,
+  playSession: PlaySession,
+  position: number,
+  finishTime?: number,
+  abandonTime?: number,
+}
+
+// the variant is left out on purpose, so options don't give away the true completion
+export interface PresentationOption {
+  presentationOptionId: number,
+  sectionText: string,
+}
+
+export interface Presentation {
+  presentationId: number,
+  creationTime: number,
+  creatorUserId: number,
+  playSessionId: number,
+  article: Article,
+  position: number,
+  context: string[],
+  options: PresentationOption[],
+}
+
+export interface Guess {
+  guessId: number,
+  creationTime: number,
+  creatorUserId: number,
+  article: Article,
+  position: number,
+  presentationId: number,
+  presentationOptionId: number,
+  articleSectionId: number,
+  correct: boolean,
+  points: number,
+  latency: number,
+  confidence?: number,
+  brierScore?: number,
+  solutionPresentationOptionId?: number,
+  rankingId?: number,
+}
+
 export const AppErrorCodes = [
   "NO_CAPABILITY",
+  "GUEST_NONEXISTENT",
+  "GUEST_ALREADY_CLAIMED",
+  "GUESTS_DISABLED",
   "ARTICLE_NONEXISTENT",
+  "ARTICLE_DATA_NONEXISTENT",
+  "ARTICLE_NOT_FINISHED",
   "ARTICLE_SECTION_NONEXISTENT",
+  "GENERATOR_NONEXISTENT",
+  "PLAY_SESSION_NONEXISTENT",
+  "PLAY_SESSION_CLOSED",
+  "DAILY_CHALLENGE_NONEXISTENT",
+  "DAILY_CHALLENGE_ALREADY_ATTEMPTED",
+  "DAILY_CHALLENGE_NOT_FINISHED",
+  "PRESENTATION_NONEXISTENT",
+  "PRESENTATION_OUTDATED",
+  "PRESENTATION_OPTION_NONEXISTENT",
+  "PRESENTATION_ALREADY_GUESSED",
+  "INVALID_RANKING",
+  "INVALID_CONFIDENCE",
+  "GUESS_NONEXISTENT",
+  "INVALID_ANNOTATION",
   "INVALID_DURATION",
   "INVALID_POSITION",
+  "INVALID_ARTICLE_IMPORT",
+  "INVALID_REVISION",
+  "DIFF_TOO_LARGE",
+  "INVALID_TAG",
   "DECODE_ERROR",
   "INTERNAL_SERVER_ERROR",
   "METHOD_NOT_ALLOWED",
@@ -197,3 +275,35 @@ export function articleSectionViewPublic(props: ArticleSectionViewPublicProps, s
   return fetchApiOrNetworkError(undefToCriticaApi(server) + "article_section/view_public", props);
 }
 
+export function guestNew(server?: string): Promise<Result<Guest, AppErrorCode>> {
+  return fetchApiOrNetworkError(undefToCriticaApi(server) + "guest/new", {});
+}
+
+export interface PlaySessionNewProps {
+  articleId: number,
+  forcedChoice: boolean,
+  apiKey: string,
+}
+
+export function playSessionNew(props: PlaySessionNewProps, server?: string): Promise<Result<PlaySessionData, AppErrorCode>> {
+  return fetchApiOrNetworkError(undefToCriticaApi(server) + "play_session/new", props);
+}
+
+export interface PresentationNewProps {
+  playSessionId: number,
+  apiKey: string,
+}
+
+export function presentationNew(props: PresentationNewProps, server?: string): Promise<Result<Presentation, AppErrorCode>> {
+  return fetchApiOrNetworkError(undefToCriticaApi(server) + "presentation/new", props);
+}
+
+export interface GuessNewProps {
+  presentationOptionId: number,
+  confidence?: number,
+  apiKey: string,
+}
+
+export function guessNew(props: GuessNewProps, server?: string): Promise<Result<Guess, AppErrorCode>> {
+  return fetchApiOrNetworkError(undefToCriticaApi(server) + "guess/new", props);
+}